@@ -0,0 +1,170 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a run of states starts repeating: the state after `start + length` steps is the
+/// one after `start` steps, and `length` is as short as it can be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The fewest steps that land on the same state as `n` steps do.
+    pub fn reduce(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.length,
+        }
+    }
+
+    /// Finds the cycle with Brent's algorithm, which keeps only a couple of states at a time
+    /// and so suits states that are large or can't be hashed, at the cost of more steps.
+    ///
+    /// Never returns if the states don't repeat.
+    pub fn brent<S: Eq + Clone>(start: S, mut step: impl FnMut(&S) -> S) -> Self {
+        // find the length by letting the hare run ahead in ever doubling stretches
+        let (mut power, mut length) = (1, 1);
+        let mut tortoise = start.clone();
+        let mut hare = step(&start);
+        while tortoise != hare {
+            if power == length {
+                tortoise = hare.clone();
+                power *= 2;
+                length = 0;
+            }
+            hare = step(&hare);
+            length += 1;
+        }
+
+        // then walk two states `length` apart until they meet at the start of the cycle
+        let mut tortoise = start.clone();
+        let mut hare = (0..length).fold(start, |state, _| step(&state));
+        let mut first = 0;
+        while tortoise != hare {
+            tortoise = step(&tortoise);
+            hare = step(&hare);
+            first += 1;
+        }
+        Self {
+            start: first,
+            length,
+        }
+    }
+}
+
+/// Every state from the first until one repeats, so any later state can be looked up.
+#[derive(Debug, Clone)]
+pub struct Orbit<S> {
+    states: Vec<S>,
+    cycle: Cycle,
+}
+
+impl<S: Hash + Eq + Clone> Orbit<S> {
+    /// Steps on from `start` until a state comes round again.
+    ///
+    /// Never returns if the states don't repeat.
+    pub fn trace(start: S, mut step: impl FnMut(&S) -> S) -> Self {
+        let mut seen = HashMap::new();
+        let mut states = vec![start];
+        loop {
+            let state = states.last().expect("there is always a first state");
+            if let Some(&first) = seen.get(state) {
+                states.pop();
+                let length = states.len() - first;
+                return Self {
+                    states,
+                    cycle: Cycle {
+                        start: first,
+                        length,
+                    },
+                };
+            }
+            seen.insert(state.clone(), states.len() - 1);
+            let next = step(state);
+            states.push(next);
+        }
+    }
+}
+
+impl<S> Orbit<S> {
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// The state after `n` steps.
+    pub fn nth(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+
+    /// States in the order they came, up to the first repeat.
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+}
+
+/// The state after `n` steps from `start`, finding the cycle with [`Cycle::brent`] rather than
+/// remembering every state on the way.
+pub fn after<S: Eq + Clone>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = Cycle::brent(start.clone(), &mut step);
+    (0..cycle.reduce(n)).fold(start, |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    /// 0, 1, 2, then round 3, 4, 5, 6, 7 and back to 3.
+    fn lollipop(n: &u32) -> u32 {
+        match n {
+            7 => 3,
+            n => n + 1,
+        }
+    }
+
+    #[test]
+    fn traces_a_lollipop() {
+        let orbit = Orbit::trace(0, lollipop);
+        assert_eq!(
+            orbit.cycle(),
+            Cycle {
+                start: 3,
+                length: 5
+            }
+        );
+        assert_eq!(orbit.states(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(*orbit.nth(2), 2);
+        assert_eq!(*orbit.nth(8), 3);
+        assert_eq!(*orbit.nth(1_000_000_000), 3 + (1_000_000_000 - 3) % 5);
+    }
+
+    #[rstest]
+    #[case(0, Cycle { start: 3, length: 5 })]
+    #[case(5, Cycle { start: 0, length: 5 })]
+    fn brent_agrees_with_trace(#[case] start: u32, #[case] expected: Cycle) {
+        assert_eq!(Cycle::brent(start, lollipop), expected);
+        assert_eq!(Orbit::trace(start, lollipop).cycle(), expected);
+    }
+
+    #[test]
+    fn finds_fixed_points() {
+        let cycle = Cycle::brent(10u32, |n| (n / 2).max(1));
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 3,
+                length: 1
+            }
+        );
+        assert_eq!(after(10u32, |n| (n / 2).max(1), 1 << 40), 1);
+    }
+
+    #[test]
+    fn jumps_far_ahead() {
+        // x -> x^2 + 1 mod 255, which falls into a cycle after a few steps
+        let step = |x: &u64| (x * x + 1) % 255;
+        let slow = (0..10_000).fold(3, |x, _| step(&x));
+        assert_eq!(after(3, step, 10_000), slow);
+        assert_eq!(*Orbit::trace(3, step).nth(10_000), slow);
+    }
+}
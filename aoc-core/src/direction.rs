@@ -0,0 +1,164 @@
+/// A compass heading on a grid whose `y` axis grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// Which way to face next, relative to the current heading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Straight,
+    Right,
+}
+
+impl Direction {
+    /// Every heading, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Straight => self,
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    /// Change in `(x, y)` for one step.
+    pub fn delta(self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    /// Moves one step on an unbounded plane.
+    pub fn step(self, (x, y): (i64, i64)) -> (i64, i64) {
+        let (dx, dy) = self.delta();
+        (x + dx, y + dy)
+    }
+
+    /// Moves one step, or `None` when that would go below zero or overflow.
+    pub fn checked_step(self, (x, y): (usize, usize)) -> Option<(usize, usize)> {
+        let (dx, dy) = self.delta();
+        Some((
+            x.checked_add_signed(dx as isize)?,
+            y.checked_add_signed(dy as isize)?,
+        ))
+    }
+
+    /// Moves one step on a `(width, height)` map, coming back in on the opposite edge.
+    pub fn wrapping_step(
+        self,
+        (x, y): (usize, usize),
+        (width, height): (usize, usize),
+    ) -> (usize, usize) {
+        let (dx, dy) = self.delta();
+        (
+            (x as i64 + dx).rem_euclid(width as i64) as usize,
+            (y as i64 + dy).rem_euclid(height as i64) as usize,
+        )
+    }
+
+    /// Reads `U`, `D`, `L` or `R`.
+    pub fn from_udlr(c: char) -> Option<Self> {
+        match c {
+            'U' => Some(Direction::North),
+            'R' => Some(Direction::East),
+            'D' => Some(Direction::South),
+            'L' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Reads `N`, `E`, `S` or `W`.
+    pub fn from_nesw(c: char) -> Option<Self> {
+        match c {
+            'N' => Some(Direction::North),
+            'E' => Some(Direction::East),
+            'S' => Some(Direction::South),
+            'W' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Reads the last digit of a day 18 colour code: `0` right, `1` down, `2` left, `3` up.
+    pub fn from_hex_digit(c: char) -> Option<Self> {
+        match c {
+            '0' => Some(Direction::East),
+            '1' => Some(Direction::South),
+            '2' => Some(Direction::West),
+            '3' => Some(Direction::North),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_round() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.turn(Turn::Straight), dir);
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+    }
+
+    #[test]
+    fn steps() {
+        assert_eq!(Direction::West.step((0, 0)), (-1, 0));
+        assert_eq!(Direction::North.checked_step((3, 0)), None);
+        assert_eq!(Direction::South.checked_step((3, 0)), Some((3, 1)));
+        assert_eq!(Direction::West.wrapping_step((0, 2), (5, 5)), (4, 2));
+        assert_eq!(Direction::South.wrapping_step((0, 4), (5, 5)), (0, 0));
+    }
+
+    #[test]
+    fn parses_each_notation() {
+        assert_eq!(Direction::from_udlr('U'), Some(Direction::North));
+        assert_eq!(Direction::from_nesw('W'), Some(Direction::West));
+        assert_eq!(Direction::from_hex_digit('1'), Some(Direction::South));
+        assert_eq!(Direction::from_hex_digit('4'), None);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{Direction, Solution};
+
+/// A day that can make up puzzle inputs of its own.
+pub trait Generate: Solution {
+    /// A random but well-formed input, growing with `size` roughly as the number of lines
+    /// or the side of the grid does.
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String;
+}
+
+/// Lattice points around a random tree-shaped region, in order and clockwise from the top left.
+///
+/// The region is grown on a `size` by `size` grid of rooms joined by two cell wide
+/// corridors, so the loop never touches itself and always has points inside it.
+pub fn outline<R: Rng>(rng: &mut R, size: usize) -> Vec<(i64, i64)> {
+    let size = size.max(1) as i64;
+    let room = (rng.gen_range(0..size), rng.gen_range(0..size));
+    let mut rooms = HashSet::from([room]);
+    let mut region = HashSet::new();
+    let mut doors = Direction::ALL.map(|dir| (room, dir)).to_vec();
+
+    let mut carve = |(x, y): (i64, i64)| {
+        for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            region.insert((2 * x + dx, 2 * y + dy));
+        }
+    };
+    carve((2 * room.0, 2 * room.1));
+
+    let target = (size * size + 1) / 2;
+    while (rooms.len() as i64) < target {
+        let (from, dir) = doors.swap_remove(rng.gen_range(0..doors.len()));
+        let to = dir.step(from);
+        if !(0..size).contains(&to.0) || !(0..size).contains(&to.1) || !rooms.insert(to) {
+            continue;
+        }
+        carve((2 * to.0, 2 * to.1));
+        carve((from.0 + to.0, from.1 + to.1));
+        let mut more = Direction::ALL.map(|dir| (to, dir));
+        more.shuffle(rng);
+        doors.extend(more);
+    }
+
+    trace_outline(&region)
+}
+
+/// Walks round the edge of a region of unit cells that has no holes and no cells meeting
+/// only at a corner.
+fn trace_outline(region: &HashSet<(i64, i64)>) -> Vec<(i64, i64)> {
+    let is_edge = |(x, y): (i64, i64), dir: Direction| {
+        // the two cells either side of the unit edge leaving (x, y)
+        let (a, b) = match dir {
+            Direction::East => ((x, y - 1), (x, y)),
+            Direction::West => ((x - 1, y - 1), (x - 1, y)),
+            Direction::South => ((x - 1, y), (x, y)),
+            Direction::North => ((x - 1, y - 1), (x, y - 1)),
+        };
+        region.contains(&a) != region.contains(&b)
+    };
+
+    let start = *region
+        .iter()
+        .min_by_key(|(x, y)| (*y, *x))
+        .expect("region is never empty");
+    let mut points = vec![start];
+    let mut dir = Direction::East;
+    let mut at = dir.step(start);
+    while at != start {
+        points.push(at);
+        dir = [dir.turn_right(), dir, dir.turn_left()]
+            .into_iter()
+            .find(|&next| is_edge(at, next))
+            .expect("every point on the outline has a way on");
+        at = dir.step(at);
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn outlines_a_simple_loop() {
+        for seed in 0..20 {
+            let points = outline(&mut StdRng::seed_from_u64(seed), 6);
+            let distinct = points.iter().collect::<HashSet<_>>();
+            assert_eq!(distinct.len(), points.len(), "seed {seed} crosses itself");
+            for (a, b) in points.iter().zip(points.iter().cycle().skip(1)) {
+                assert_eq!((a.0 - b.0).abs() + (a.1 - b.1).abs(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn traces_a_square() {
+        let region = HashSet::from([(0, 0), (1, 0), (0, 1), (1, 1)]);
+        assert_eq!(
+            trace_outline(&region),
+            vec![
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (1, 2),
+                (0, 2),
+                (0, 1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use std::fmt::Display;
+
+use crate::{parsers, Direction, ParseError, Source};
+
+/// A grid cell that is written as a single character in the puzzle input.
+pub trait Tile: Sized {
+    /// Describes the accepted characters, for parse errors.
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+impl Tile for char {
+    const EXPECTED: &'static str = "a character";
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// A rectangular map, stored row by row and indexed by `(x, y)` from the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, or `None` if they differ in length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Option<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for row in rows {
+            if *width.get_or_insert(row.len()) != row.len() {
+                return None;
+            }
+            cells.extend(row);
+            height += 1;
+        }
+        Some(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Position of the first cell, row by row, that matches `f`.
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, cell)| f(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact panics on a zero chunk size, and an empty grid has no rows anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// In-bounds positions directly above, right of, below and left of `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(x, y, dir))
+    }
+
+    /// The position one step from `(x, y)`, if it is still on the grid.
+    pub fn step(&self, x: usize, y: usize, dir: Direction) -> Option<(usize, usize)> {
+        dir.checked_step((x, y))
+            .filter(|(x, y)| *x < self.width && *y < self.height)
+    }
+
+    /// In-bounds positions around `(x, y)`, diagonals included.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dy)| self.offset(x, y, dx, dy))
+    }
+
+    fn offset(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|x| *x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|y| *y < self.height)?;
+        Some((x, y))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Turns the grid a quarter turn anticlockwise, so the right column becomes the top row.
+    pub fn rotate_left(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Turns the grid a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_right(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| (0..self.height).rev().map(move |y| &self.row(y)[x]))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl<T: Tile> Grid<T> {
+    /// Reads every line of `text`, a slice of `source`, as a row.
+    pub fn parse(source: &Source, text: &str) -> Result<Self, ParseError> {
+        source.run(parsers::grid, text)
+    }
+}
+
+impl<T: Tile> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            let line = row.iter().map(Tile::to_char).collect::<String>();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Rock {
+        Empty,
+        Round,
+    }
+
+    impl Tile for Rock {
+        const EXPECTED: &'static str = "'.' or 'O'";
+
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '.' => Some(Rock::Empty),
+                'O' => Some(Rock::Round),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            match self {
+                Rock::Empty => '.',
+                Rock::Round => 'O',
+            }
+        }
+    }
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(&Source::new(0, text), text).unwrap()
+    }
+
+    #[test]
+    fn reports_bad_tile() {
+        let text = "..\n.?";
+        let err = Grid::<Rock>::parse(&Source::new(14, text), text).unwrap_err();
+        assert_eq!(err, ParseError::new(14, 2, 2, "'.' or 'O'"));
+    }
+
+    #[test]
+    fn reports_ragged_row_within_source() {
+        let text = "header\n..\n.\n..";
+        let err = Grid::<Rock>::parse(&Source::new(14, text), &text[7..]).unwrap_err();
+        assert_eq!(err, ParseError::new(14, 3, 1, "a row of 2 tiles"));
+    }
+
+    #[test]
+    fn gets_in_bounds_only() {
+        let g = grid("ab\ncd");
+        assert_eq!(g.get(1, 1), Some(&'d'));
+        assert_eq!(g.get(2, 0), None);
+        assert_eq!(g.get(0, 2), None);
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let g = grid("abc\ndef\nghi");
+        assert_eq!(
+            g.neighbours4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(g.neighbours8(1, 1).count(), 8);
+        assert_eq!(g.neighbours8(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(g.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(g.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(g.rotate_left().rotate_right(), g);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.row(1), &['d', 'e', 'f']);
+        assert_eq!(g.column(2).collect::<String>(), "cf");
+        assert_eq!(g.columns().count(), 3);
+    }
+
+    #[test]
+    fn displays_tiles() {
+        let text = "O.\n.O";
+        let g = Grid::<Rock>::parse(&Source::new(14, text), text).unwrap();
+        assert_eq!(g.to_string(), "O.\n.O\n");
+    }
+}
@@ -0,0 +1,298 @@
+use std::ops::Range;
+
+/// A set of numbers, kept as sorted half-open ranges that neither overlap nor touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<Range<u64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ranges(&self) -> &[Range<u64>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many numbers are in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        // the last range starting at or before the value is the only one that can hold it
+        let after = self.ranges.partition_point(|range| range.start <= value);
+        after > 0 && value < self.ranges[after - 1].end
+    }
+
+    pub fn insert(&mut self, range: Range<u64>) {
+        *self = self.union(&Self::from(range));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // whichever ends first can't overlap anything further along
+            match a.end < b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Numbers in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut cuts = other.ranges.iter().peekable();
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(cut) = cuts.peek() {
+                if cut.end <= start {
+                    cuts.next();
+                    continue;
+                }
+                if cut.start >= range.end {
+                    break;
+                }
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = cut.end;
+                if start >= range.end {
+                    break;
+                }
+                cuts.next();
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The numbers below `at`, and those from `at` up.
+    pub fn split_at(&self, at: u64) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for range in &self.ranges {
+            match range {
+                range if range.end <= at => below.push(range.clone()),
+                range if range.start >= at => above.push(range.clone()),
+                range => {
+                    below.push(range.start..at);
+                    above.push(at..range.end);
+                }
+            }
+        }
+        (Self { ranges: below }, Self { ranges: above })
+    }
+}
+
+impl From<Range<u64>> for IntervalSet {
+    fn from(range: Range<u64>) -> Self {
+        [range].into_iter().collect()
+    }
+}
+
+impl FromIterator<Range<u64>> for IntervalSet {
+    /// Takes ranges in any order, merging those that overlap or touch.
+    fn from_iter<I: IntoIterator<Item = Range<u64>>>(iter: I) -> Self {
+        let mut sorted = iter
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect::<Vec<_>>();
+        sorted.sort_unstable_by_key(|range| range.start);
+
+        let mut ranges = Vec::<Range<u64>>::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+        Self { ranges }
+    }
+}
+
+/// A mapping that moves each of its pieces to a new start, leaving numbers outside every
+/// piece where they are.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    /// Disjoint source ranges, sorted, each with where its first number goes.
+    pieces: Vec<(Range<u64>, u64)>,
+}
+
+impl PiecewiseMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `source` onto the range starting at `target`, except where an earlier piece
+    /// already covers it.
+    pub fn insert(&mut self, source: Range<u64>, target: u64) {
+        let uncovered = IntervalSet::from(source.clone()).difference(&self.domain());
+        for range in uncovered.ranges {
+            let target = target + (range.start - source.start);
+            self.pieces.push((range, target));
+        }
+        self.pieces.sort_unstable_by_key(|(range, _)| range.start);
+    }
+
+    /// Every number some piece moves.
+    pub fn domain(&self) -> IntervalSet {
+        self.pieces.iter().map(|(range, _)| range.clone()).collect()
+    }
+
+    pub fn get(&self, value: u64) -> u64 {
+        let after = self
+            .pieces
+            .partition_point(|(range, _)| range.start <= value);
+        match after.checked_sub(1).map(|i| &self.pieces[i]) {
+            Some((range, target)) if value < range.end => target + (value - range.start),
+            _ => value,
+        }
+    }
+
+    /// Where every number of `set` ends up.
+    pub fn map(&self, set: &IntervalSet) -> IntervalSet {
+        let moved = self.pieces.iter().flat_map(|(source, target)| {
+            set.intersection(&IntervalSet::from(source.clone()))
+                .ranges
+                .into_iter()
+                .map(move |range| {
+                    let start = target + (range.start - source.start);
+                    start..start + (range.end - range.start)
+                })
+        });
+        let unmoved = set.difference(&self.domain()).ranges;
+        moved.chain(unmoved).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    /// Builds a set from `(start, end)` pairs, each end exclusive.
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet {
+        ranges.iter().map(|&(start, end)| start..end).collect()
+    }
+
+    /// The numbers below 64 in a set, as bits.
+    fn bits(set: &IntervalSet) -> u64 {
+        (0..64).filter(|&n| set.contains(n)).map(|n| 1 << n).sum()
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching_ranges() {
+        let set = set(&[(8, 10), (1, 3), (2, 5), (5, 6), (7, 7)]);
+        assert_eq!(set.ranges(), vec![1..6, 8..10]);
+        assert_eq!(set.len(), 7);
+        assert_eq!(set.min(), Some(1));
+        assert!(set.contains(5) && !set.contains(6) && !set.contains(10));
+    }
+
+    #[rstest]
+    #[case(&[(7, 11)], 12, &[(7, 11)], &[])]
+    #[case(&[(7, 11)], 10, &[(7, 10)], &[(10, 11)])]
+    #[case(&[(7, 11)], 7, &[], &[(7, 11)])]
+    #[case(&[(1, 3), (5, 9)], 6, &[(1, 3), (5, 6)], &[(6, 9)])]
+    fn splits(
+        #[case] ranges: &[(u64, u64)],
+        #[case] at: u64,
+        #[case] below: &[(u64, u64)],
+        #[case] above: &[(u64, u64)],
+    ) {
+        assert_eq!(set(ranges).split_at(at), (set(below), set(above)));
+    }
+
+    #[test]
+    fn maps_pieces() {
+        // seed-to-soil from the day 5 example
+        let mut map = PiecewiseMap::new();
+        map.insert(98..100, 50);
+        map.insert(50..98, 52);
+        assert_eq!([79, 14, 55, 13].map(|n| map.get(n)), [81, 14, 57, 13]);
+        assert_eq!(map.map(&set(&[(79, 93)])), set(&[(81, 95)]));
+        assert_eq!(
+            map.map(&set(&[(90, 110)])),
+            set(&[(50, 52), (92, 100), (100, 110)])
+        );
+    }
+
+    #[test]
+    fn keeps_the_first_piece_where_they_overlap() {
+        let mut map = PiecewiseMap::new();
+        map.insert(10..20, 100);
+        map.insert(15..25, 200);
+        assert_eq!(map.get(15), 105);
+        assert_eq!(map.get(22), 207);
+        assert_eq!(map.domain(), set(&[(10, 25)]));
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<(u64, u64)>> {
+        prop::collection::vec((0u64..64, 0u64..16), 0..6).prop_map(|pairs| {
+            pairs
+                .into_iter()
+                .map(|(s, n)| (s, (s + n).min(64)))
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_bit_sets(a in ranges(), b in ranges(), at in 0u64..64) {
+            let (a, b) = (set(&a), set(&b));
+            prop_assert_eq!(bits(&a.union(&b)), bits(&a) | bits(&b));
+            prop_assert_eq!(bits(&a.intersection(&b)), bits(&a) & bits(&b));
+            prop_assert_eq!(bits(&a.difference(&b)), bits(&a) & !bits(&b));
+            let (below, above) = a.split_at(at);
+            prop_assert_eq!(bits(&below) | bits(&above), bits(&a));
+            prop_assert!(below.ranges().iter().all(|r| r.end <= at));
+            prop_assert!(above.ranges().iter().all(|r| r.start >= at));
+            prop_assert_eq!(a.len(), u64::from(bits(&a).count_ones()));
+        }
+
+        #[test]
+        fn maps_sets_as_every_number(
+            pieces in prop::collection::vec((0u64..64, 1u64..16, 0u64..64), 0..4),
+            values in ranges(),
+        ) {
+            let mut map = PiecewiseMap::new();
+            for (start, len, target) in pieces {
+                map.insert(start..start + len, target);
+            }
+            let values = set(&values);
+            let each = (0..64)
+                .filter(|&n| values.contains(n))
+                .map(|n| map.get(n)..map.get(n) + 1)
+                .collect::<IntervalSet>();
+            prop_assert_eq!(map.map(&values), each);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::{error::Error, fmt::Display};
+
+mod cycle;
+mod direction;
+mod generate;
+mod grid;
+mod interval;
+mod parse;
+pub mod parsers;
+mod render;
+mod search;
+mod step;
+
+pub use cycle::{after, Cycle, Orbit};
+pub use direction::{Direction, Turn};
+pub use generate::{outline, Generate};
+pub use grid::{Grid, Tile};
+pub use interval::{IntervalSet, PiecewiseMap};
+pub use parse::{ParseError, Source};
+pub use render::{Animate, Image, Paint, Rgb};
+pub use search::{astar, bfs, dijkstra, Found, SearchState};
+pub use step::{Event, Step};
+
+/// A single day's puzzle: one shared parse step, then the two parts.
+pub trait Solution {
+    /// Day of the month the puzzle was released on.
+    const DAY: u8;
+
+    /// Parsed puzzle input, which may borrow from the raw input text.
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable>;
+}
+
+/// Why a part has no answer for an input that parsed, such as a network with nothing
+/// sending to `rx`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unsolvable {
+    pub day: u8,
+    pub reason: String,
+}
+
+impl Unsolvable {
+    pub fn new(day: u8, reason: impl Into<String>) -> Self {
+        Self {
+            day,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for Unsolvable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {:02}: no answer, {}", self.day, self.reason)
+    }
+}
+
+impl Error for Unsolvable {}
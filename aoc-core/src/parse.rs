@@ -0,0 +1,105 @@
+use std::{error::Error, fmt::Display};
+
+use nom::Parser;
+
+use crate::parsers::Expected;
+
+/// Where and why a puzzle input failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// What the parser was looking for at that position.
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(day: u8, line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {:02}, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Raw puzzle input for one day, used to turn sub-slices back into positions.
+///
+/// Every token a parser looks at is a slice of the original text, so errors can
+/// be built from the offending slice alone.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    pub day: u8,
+    pub text: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(day: u8, text: &'a str) -> Self {
+        Self { day, text }
+    }
+
+    /// Builds an error pointing at the start of `at`, which must be a slice of the input.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (at.as_ptr() as usize)
+            .saturating_sub(self.text.as_ptr() as usize)
+            .min(self.text.len());
+        debug_assert!(self.text.is_char_boundary(offset));
+        let before = &self.text[..offset];
+
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        ParseError::new(self.day, line, column, expected)
+    }
+
+    /// Builds an error pointing just past the end of `at`.
+    pub fn error_after(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        self.error(&at[at.len()..], expected)
+    }
+
+    /// Runs `parser` over `text`, a slice of the input, which it must consume up to any
+    /// trailing line breaks.
+    pub fn run<T>(
+        &self,
+        mut parser: impl Parser<&'a str, T, Expected<'a>>,
+        text: &'a str,
+    ) -> Result<T, ParseError> {
+        let (rest, value) = parser.parse(text).map_err(|err| match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => self.error(e.at, e.what),
+            nom::Err::Incomplete(_) => self.error_after(text, "more input"),
+        })?;
+        let rest = rest.trim_start_matches(['\r', '\n']);
+        match rest.is_empty() {
+            true => Ok(value),
+            false => Err(self.error(rest, "the end of the input")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_line_and_column() {
+        let text = "abc\nde fg\nh";
+        let source = Source::new(3, text);
+        let at = &text[7..];
+        assert_eq!(source.error(at, "x"), ParseError::new(3, 2, 4, "x"));
+    }
+}
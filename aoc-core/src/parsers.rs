@@ -0,0 +1,283 @@
+use std::str::FromStr;
+
+use nom::{
+    character::complete::{char, digit1, line_ending, space0, space1},
+    combinator::{cut, opt, recognize},
+    error::ErrorKind,
+    multi::separated_list1,
+    sequence::{pair, separated_pair},
+    Err, Parser,
+};
+
+use crate::{Grid, Tile};
+
+/// Where a parser gave up, as a slice of the input, and what it wanted to find there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected<'a> {
+    pub at: &'a str,
+    pub what: String,
+}
+
+impl<'a> Expected<'a> {
+    pub fn new(at: &'a str, what: impl Into<String>) -> Self {
+        Self {
+            at,
+            what: what.into(),
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Expected<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        let what = match kind {
+            ErrorKind::Eof => "the end of the input",
+            ErrorKind::CrLf => "a line break",
+            ErrorKind::Space | ErrorKind::MultiSpace => "a space",
+            ErrorKind::Digit => "a digit",
+            ErrorKind::Alpha => "a letter",
+            ErrorKind::AlphaNumeric => "a letter or digit",
+            _ => "something else",
+        };
+        Self::new(input, what)
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    /// Of two failed alternatives, the one that got further says more about what went wrong.
+    fn or(self, other: Self) -> Self {
+        match other.at.len() < self.at.len() {
+            true => other,
+            false => self,
+        }
+    }
+}
+
+impl<'a, E> nom::error::FromExternalError<&'a str, E> for Expected<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
+
+pub type IResult<'a, T> = nom::IResult<&'a str, T, Expected<'a>>;
+
+/// Reports a recoverable failure of `parser` as expecting `what`, wherever it stopped.
+///
+/// Failures past a [`cut`] already say what went wrong and are passed on as they are.
+pub fn expect<'a, T>(
+    what: &'static str,
+    mut parser: impl Parser<&'a str, T, Expected<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    move |input| {
+        parser.parse(input).map_err(|err| match err {
+            Err::Error(e) => Err::Error(Expected::new(e.at, what)),
+            err => err,
+        })
+    }
+}
+
+/// Exactly `text`, which is what gets reported as expected if it isn't there.
+pub fn tag<'a>(text: &'static str) -> impl FnMut(&'a str) -> IResult<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(text) {
+        Some(rest) => Ok((rest, &input[..text.len()])),
+        None => Err(Err::Error(Expected::new(input, format!("{text:?}")))),
+    }
+}
+
+/// The end of a line or of the input, without consuming anything.
+pub fn eol(input: &str) -> IResult<'_, ()> {
+    match input.is_empty() || input.starts_with('\n') || input.starts_with("\r\n") {
+        true => Ok((input, ())),
+        false => Err(Err::Error(Expected::new(input, "the end of the line"))),
+    }
+}
+
+/// A line break and then an empty line, as between blocks.
+pub fn blank_line(input: &str) -> IResult<'_, ()> {
+    let (rest, _) = expect("a blank line", pair(line_ending, line_ending))(input)?;
+    Ok((rest, ()))
+}
+
+/// The rest of a line, which must not be empty.
+pub fn line(input: &str) -> IResult<'_, &str> {
+    let end = input.find(['\r', '\n']).unwrap_or(input.len());
+    match end {
+        0 => Err(Err::Error(Expected::new(input, "a line"))),
+        end => Ok((&input[end..], &input[..end])),
+    }
+}
+
+/// A whole number, with a leading `-` if it is negative.
+pub fn integer<'a, T: FromStr>(input: &'a str) -> IResult<'a, T> {
+    let not_a_number = || Err::Error(Expected::new(input, "a number"));
+    let (rest, digits) = recognize(pair(opt(char('-')), digit1::<_, Expected>))(input)
+        .map_err(|_| not_a_number())?;
+    let value = digits.parse().map_err(|_| not_a_number())?;
+    Ok((rest, value))
+}
+
+/// Whole numbers separated by spaces, where anything after a space that isn't a number is
+/// reported as expecting `what`.
+pub fn integers<'a, T: FromStr>(what: &'static str) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(space1, cut(expect(what, integer)))
+}
+
+/// `key: values`, with any spaces after the colon.
+pub fn header<'a, K, V>(
+    key: impl Parser<&'a str, K, Expected<'a>>,
+    values: impl Parser<&'a str, V, Expected<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, (K, V)> {
+    separated_pair(key, pair(tag(":"), space0), values)
+}
+
+/// `from -> a, b`: a name and every name it points at.
+pub fn edge<'a, F, T>(
+    from: impl Parser<&'a str, F, Expected<'a>>,
+    to: impl Parser<&'a str, T, Expected<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, (F, Vec<T>)> {
+    separated_pair(from, tag(" -> "), separated_list1(tag(", "), cut(to)))
+}
+
+/// One `item` per line, each of which must fill its line, up to a blank line or the end of
+/// the input.
+///
+/// Once lines start, any that doesn't parse is an error rather than the end of the list.
+pub fn lines<'a, T>(
+    mut item: impl Parser<&'a str, T, Expected<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    move |mut input| {
+        let mut items = Vec::new();
+        loop {
+            let (rest, value) = cut(|i| item.parse(i))(input)?;
+            let (rest, _) = cut(eol)(rest)?;
+            items.push(value);
+            match line_ending::<_, Expected>(rest) {
+                Ok((next, _)) if !next.is_empty() && eol(next).is_err() => input = next,
+                _ => return Ok((rest, items)),
+            }
+        }
+    }
+}
+
+/// Blocks separated by blank lines, such as the maps in an almanac.
+pub fn blocks<'a, T>(
+    mut item: impl Parser<&'a str, T, Expected<'a>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    move |mut input| {
+        let mut items = Vec::new();
+        loop {
+            let (rest, value) = cut(|i| item.parse(i))(input)?;
+            items.push(value);
+            match blank_line(rest) {
+                Ok((next, _)) if !next.trim().is_empty() => input = next,
+                _ => return Ok((rest, items)),
+            }
+        }
+    }
+}
+
+/// A rectangle of tiles, one row per line.
+pub fn grid<'a, T: Tile>(input: &'a str) -> IResult<'a, Grid<T>> {
+    let row = |input: &'a str| -> IResult<'a, (&'a str, Vec<T>)> {
+        let (rest, text) = expect(T::EXPECTED, line)(input)?;
+        let tiles = text
+            .char_indices()
+            .map(|(i, c)| T::from_char(c).ok_or(&text[i..]))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|at| Err::Failure(Expected::new(at, T::EXPECTED)))?;
+        Ok((rest, (text, tiles)))
+    };
+
+    let (rest, rows) = lines(row)(input)?;
+    let width = rows[0].1.len();
+    if let Some((text, _)) = rows.iter().find(|(_, tiles)| tiles.len() != width) {
+        let ragged = Expected::new(text, format!("a row of {width} tiles"));
+        return Err(Err::Failure(ragged));
+    }
+    let grid = Grid::from_rows(rows.into_iter().map(|(_, tiles)| tiles));
+    Ok((rest, grid.expect("rows were checked to be the same length")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Source};
+
+    fn run<'a, T>(
+        parser: impl Parser<&'a str, T, Expected<'a>>,
+        text: &'a str,
+    ) -> Result<T, ParseError> {
+        Source::new(0, text).run(parser, text)
+    }
+
+    #[test]
+    fn reads_integers() {
+        assert_eq!(
+            run(integers::<i64>("a reading"), "0 -3  6"),
+            Ok(vec![0, -3, 6])
+        );
+        assert_eq!(
+            run(integers::<u8>("a reading"), "1 -3"),
+            Err(ParseError::new(0, 1, 3, "a reading"))
+        );
+        assert_eq!(
+            run(integer::<u8>, "256"),
+            Err(ParseError::new(0, 1, 1, "a number"))
+        );
+    }
+
+    #[test]
+    fn reads_headers_and_edges() {
+        let parser = header(tag("Time"), integers::<u32>("a time"));
+        assert_eq!(run(parser, "Time:   7 15"), Ok(("Time", vec![7, 15])));
+        let parser = edge(line_name, line_name);
+        assert_eq!(run(parser, "a -> b, c"), Ok(("a", vec!["b", "c"])));
+    }
+
+    fn line_name(input: &str) -> IResult<'_, &str> {
+        nom::character::complete::alpha1(input)
+    }
+
+    #[test]
+    fn reads_lines_in_blocks() {
+        let text = "1 2\n3\n\n4\n";
+        let parser = blocks(lines(integers::<u32>("a number")));
+        assert_eq!(
+            run(parser, text),
+            Ok(vec![vec![vec![1, 2], vec![3]], vec![vec![4]]])
+        );
+    }
+
+    #[test]
+    fn reports_unfinished_lines() {
+        let parser = lines(separated_pair(integer::<u32>, tag(" "), integer::<u32>));
+        assert_eq!(
+            run(parser, "12 34\n56"),
+            Err(ParseError::new(0, 2, 3, "\" \""))
+        );
+        let parser = lines(integer::<u32>);
+        assert_eq!(
+            run(parser, "12\n34x"),
+            Err(ParseError::new(0, 2, 3, "the end of the line"))
+        );
+    }
+
+    #[test]
+    fn reports_leftover_input() {
+        assert_eq!(
+            run(lines(integer::<u32>), "1\n2\n\n3\n"),
+            Err(ParseError::new(0, 4, 1, "the end of the input"))
+        );
+        assert_eq!(run(lines(integer::<u32>), "1\n2\n\n"), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn names_failed_alternatives() {
+        let parser = expect("'L' or 'R'", nom::branch::alt((tag("L"), tag("R"))));
+        assert_eq!(
+            run(parser, "X"),
+            Err(ParseError::new(0, 1, 1, "'L' or 'R'"))
+        );
+    }
+}
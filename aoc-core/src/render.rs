@@ -0,0 +1,169 @@
+use std::fmt::Write;
+
+use crate::{Grid, Solution};
+
+/// A colour, as red, green and blue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A grid cell that has a colour of its own, so the grid can be drawn as an image.
+pub trait Paint {
+    fn paint(&self) -> Rgb;
+}
+
+/// A picture of a grid, one square of colour per cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    cells: Grid<Rgb>,
+}
+
+impl Image {
+    pub fn new<T: Paint>(grid: &Grid<T>) -> Self {
+        Self {
+            cells: grid.map(Paint::paint),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        self.cells.get(x, y).copied()
+    }
+
+    /// Paints `colour` over every one of `positions` that is on the image.
+    pub fn overlay(&mut self, positions: impl IntoIterator<Item = (usize, usize)>, colour: Rgb) {
+        for (x, y) in positions {
+            if let Some(cell) = self.cells.get_mut(x, y) {
+                *cell = colour;
+            }
+        }
+    }
+
+    /// An SVG document with each cell drawn `scale` pixels square.
+    pub fn to_svg(&self, scale: u32) -> String {
+        let (width, height) = (self.width(), self.height());
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n",
+            width * scale as usize,
+            height * scale as usize,
+        );
+        for (y, row) in self.cells.rows().enumerate() {
+            // one rect per run of the same colour keeps the file small
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                writeln!(
+                    out,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"1\" fill=\"{}\"/>",
+                    run.len(),
+                    run[0].hex()
+                )
+                .unwrap();
+                x += run.len();
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// A PNG file with each cell drawn `scale` pixels square.
+    pub fn to_png(&self, scale: u32) -> Vec<u8> {
+        let scale = scale as usize;
+        let data = self
+            .cells
+            .rows()
+            .flat_map(|row| {
+                let line = row
+                    .iter()
+                    .flat_map(|cell| [cell.0, cell.1, cell.2].repeat(scale))
+                    .collect::<Vec<_>>();
+                line.repeat(scale)
+            })
+            .collect::<Vec<u8>>();
+
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(
+            &mut out,
+            (self.width() * scale) as u32,
+            (self.height() * scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .expect("writing to memory cannot fail");
+        writer
+            .write_image_data(&data)
+            .expect("image data matches the header");
+        writer.finish().expect("writing to memory cannot fail");
+        out
+    }
+}
+
+/// A day whose solution can be watched as it unfolds.
+pub trait Animate: Solution {
+    /// One image per step of the simulation, starting with the input itself.
+    fn frames(input: &Self::Input<'_>) -> Vec<Image>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = Rgb(255, 0, 0);
+    const BLUE: Rgb = Rgb(0, 0, 255);
+
+    impl Paint for bool {
+        fn paint(&self) -> Rgb {
+            if *self {
+                RED
+            } else {
+                BLUE
+            }
+        }
+    }
+
+    fn image() -> Image {
+        Image::new(&Grid::from_rows([vec![true, true, false], vec![false, false, false]]).unwrap())
+    }
+
+    #[test]
+    fn overlays_cells_on_the_image() {
+        let mut image = image();
+        image.overlay([(2, 1), (5, 5)], RED);
+        assert_eq!(image.get(2, 1), Some(RED));
+        assert_eq!(image.get(1, 1), Some(BLUE));
+    }
+
+    #[test]
+    fn draws_runs_as_svg_rects() {
+        let svg = image().to_svg(4);
+        assert!(svg.contains("width=\"12\" height=\"8\" viewBox=\"0 0 3 2\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#ff0000\"/>"));
+        assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#0000ff\"/>"));
+        assert_eq!(svg.matches("<rect").count(), 3);
+    }
+
+    #[test]
+    fn scales_png_pixels() {
+        let png = image().to_png(2);
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (6, 4));
+        // second row of pixels is still the first row of cells
+        let row = &pixels[info.line_size..2 * info.line_size];
+        assert_eq!(&row[9..15], &[255, 0, 0, 0, 0, 255]);
+    }
+}
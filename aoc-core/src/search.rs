@@ -0,0 +1,256 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A place a search can be, knowing where it can move next and what each move costs.
+pub trait SearchState: Clone + Eq + Hash {
+    /// What the states move around in, such as a grid, passed to every method.
+    type World: ?Sized;
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    /// States one move away, with the cost of each move.
+    fn neighbours(&self, world: &Self::World) -> impl IntoIterator<Item = (Self, Self::Cost)>;
+
+    fn is_goal(&self, world: &Self::World) -> bool;
+
+    /// A guess at the cost left to reach a goal for [`astar`], which must never overestimate.
+    fn heuristic(&self, _world: &Self::World) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// The cheapest way found to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    /// Every state on the way, from the start to the goal.
+    pub path: Vec<S>,
+}
+
+/// Visited states, each with the one it was reached from, so paths can be walked back.
+struct Trail<S> {
+    nodes: Vec<(S, Option<usize>)>,
+}
+
+impl<S: Clone> Trail<S> {
+    fn push(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.nodes.push((state, parent));
+        self.nodes.len() - 1
+    }
+
+    fn path(&self, mut at: usize) -> Vec<S> {
+        let mut path = vec![self.nodes[at].0.clone()];
+        while let Some(parent) = self.nodes[at].1 {
+            path.push(self.nodes[parent].0.clone());
+            at = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Fewest moves from any of `starts` to a goal, ignoring what the moves cost.
+pub fn bfs<S: SearchState>(
+    world: &S::World,
+    starts: impl IntoIterator<Item = S>,
+) -> Option<Found<S, usize>> {
+    let mut trail = Trail { nodes: Vec::new() };
+    let mut seen = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = seen.entry(start.clone()) {
+            entry.insert(());
+            queue.push_back((trail.push(start, None), 0));
+        }
+    }
+
+    while let Some((at, moves)) = queue.pop_front() {
+        let state = trail.nodes[at].0.clone();
+        if state.is_goal(world) {
+            return Some(Found {
+                cost: moves,
+                path: trail.path(at),
+            });
+        }
+        for (next, _) in state.neighbours(world) {
+            if let Entry::Vacant(entry) = seen.entry(next.clone()) {
+                entry.insert(());
+                queue.push_back((trail.push(next, Some(at)), moves + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest way from any of `starts` to a goal, by Dijkstra's algorithm on a binary heap.
+pub fn dijkstra<S: SearchState>(
+    world: &S::World,
+    starts: impl IntoIterator<Item = S>,
+) -> Option<Found<S, S::Cost>> {
+    best_first(world, starts, |_| S::Cost::default())
+}
+
+/// Cheapest way from any of `starts` to a goal, led towards it by [`SearchState::heuristic`].
+pub fn astar<S: SearchState>(
+    world: &S::World,
+    starts: impl IntoIterator<Item = S>,
+) -> Option<Found<S, S::Cost>> {
+    best_first(world, starts, |state: &S| state.heuristic(world))
+}
+
+fn best_first<S: SearchState>(
+    world: &S::World,
+    starts: impl IntoIterator<Item = S>,
+    guess: impl Fn(&S) -> S::Cost,
+) -> Option<Found<S, S::Cost>> {
+    let mut trail = Trail { nodes: Vec::new() };
+    // the cheapest cost found to each state, and the trail entry that got there
+    let mut best = HashMap::<S, (S::Cost, usize)>::new();
+    let mut heap = BinaryHeap::new();
+
+    let zero = S::Cost::default();
+    for start in starts {
+        if best.contains_key(&start) {
+            continue;
+        }
+        let at = trail.push(start.clone(), None);
+        heap.push(Reverse((guess(&start), zero, at)));
+        best.insert(start, (zero, at));
+    }
+
+    while let Some(Reverse((_, cost, at))) = heap.pop() {
+        let state = trail.nodes[at].0.clone();
+        // a cheaper way here was queued after this one, and has been dealt with already
+        if best.get(&state).is_some_and(|&(_, latest)| latest != at) {
+            continue;
+        }
+        if state.is_goal(world) {
+            return Some(Found {
+                cost,
+                path: trail.path(at),
+            });
+        }
+        for (next, step) in state.neighbours(world) {
+            let next_cost = cost + step;
+            if best
+                .get(&next)
+                .is_some_and(|&(known, _)| known <= next_cost)
+            {
+                continue;
+            }
+            let priority = next_cost + guess(&next);
+            let next_at = trail.push(next.clone(), Some(at));
+            best.insert(next, (next_cost, next_at));
+            heap.push(Reverse((priority, next_cost, next_at)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Direction, Grid, Source, Tile};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Cost(u32);
+
+    impl Tile for Cost {
+        const EXPECTED: &'static str = "a digit or '#'";
+
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '#' => Some(Cost(0)),
+                c => c.to_digit(10).map(Cost),
+            }
+        }
+
+        fn to_char(&self) -> char {
+            char::from_digit(self.0, 10).unwrap()
+        }
+    }
+
+    /// A walker on a grid of entry costs, where `#` is a wall, heading for the bottom right.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Walker((usize, usize));
+
+    impl SearchState for Walker {
+        type World = Grid<Cost>;
+        type Cost = u32;
+
+        fn neighbours(&self, grid: &Grid<Cost>) -> impl IntoIterator<Item = (Self, u32)> {
+            Direction::ALL.into_iter().filter_map(|dir| {
+                let next = dir.checked_step(self.0)?;
+                let cost = grid.get(next.0, next.1)?.0;
+                (cost > 0).then_some((Walker(next), cost))
+            })
+        }
+
+        fn is_goal(&self, grid: &Grid<Cost>) -> bool {
+            self.0 == (grid.width() - 1, grid.height() - 1)
+        }
+
+        fn heuristic(&self, grid: &Grid<Cost>) -> u32 {
+            (grid.width() - 1 - self.0 .0 + grid.height() - 1 - self.0 .1) as u32
+        }
+    }
+
+    fn grid(text: &str) -> Grid<Cost> {
+        Grid::parse(&Source::new(0, text), text).unwrap()
+    }
+
+    const DETOUR: &str = "\
+1911
+1#11
+1111";
+
+    #[test]
+    fn finds_fewest_moves() {
+        let found = bfs(&grid(DETOUR), [Walker((0, 0))]).unwrap();
+        assert_eq!(found.cost, 5);
+        assert_eq!(found.path.len(), 6);
+        assert_eq!(found.path.first(), Some(&Walker((0, 0))));
+        assert_eq!(found.path.last(), Some(&Walker((3, 2))));
+    }
+
+    #[test]
+    fn finds_cheapest_path() {
+        let grid = grid(DETOUR);
+        let expected = Found {
+            cost: 5,
+            path: [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2)]
+                .map(Walker)
+                .to_vec(),
+        };
+        assert_eq!(dijkstra(&grid, [Walker((0, 0))]), Some(expected.clone()));
+        assert_eq!(astar(&grid, [Walker((0, 0))]), Some(expected));
+    }
+
+    #[test]
+    fn prefers_cheap_detours() {
+        // along the top costs 12, dropping down first only 4
+        let grid = grid("1911\n1111");
+        let found = astar(&grid, [Walker((0, 0))]).unwrap();
+        assert_eq!(found.cost, 4);
+        assert_eq!(found.path.len(), 5);
+    }
+
+    #[test]
+    fn reports_no_way_through() {
+        let grid = grid("11#1\n11#1");
+        assert_eq!(bfs(&grid, [Walker((0, 0))]), None);
+        assert_eq!(dijkstra(&grid, [Walker((0, 0))]), None);
+        assert_eq!(dijkstra::<Walker>(&grid, []), None);
+    }
+
+    #[test]
+    fn starts_anywhere_given() {
+        let grid = grid(DETOUR);
+        let found = dijkstra(&grid, [Walker((0, 0)), Walker((3, 0))]).unwrap();
+        assert_eq!(found.cost, 2);
+        assert_eq!(found.path.first(), Some(&Walker((3, 0))));
+    }
+}
@@ -0,0 +1,18 @@
+/// What one step of a simulation turned up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// Nothing worth stopping for.
+    Quiet,
+    /// Something worth stopping at when running to a breakpoint, and why.
+    Breakpoint(String),
+    /// There was nothing left to do, so the state is unchanged.
+    Finished,
+}
+
+/// A simulation that can be advanced one step at a time and looked at in between.
+pub trait Step: Clone {
+    fn step(&mut self) -> Event;
+
+    /// The current state, for showing in a terminal.
+    fn view(&self) -> String;
+}
@@ -0,0 +1,12 @@
+//! Number theory and sequence helpers shared between days.
+//!
+//! Everything works in `i128` and reports overflow as `None` rather than wrapping, so a
+//! day can lean on it without first checking how big its numbers get.
+
+mod number;
+mod poly;
+mod sequence;
+
+pub use number::{crt, extended_gcd, gcd, gcd_all, lcm, lcm_all};
+pub use poly::{Polynomial, Ratio};
+pub use sequence::{differences, extrapolate};
@@ -0,0 +1,165 @@
+/// Greatest common divisor, never negative; `gcd(0, 0)` is 0.
+pub fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    // only gcd(i128::MIN, 0) and its like fall outside i128, and those saturate
+    i128::try_from(a).unwrap_or(i128::MAX)
+}
+
+/// Least common multiple, never negative, or `None` if it doesn't fit; `lcm(0, n)` is 0.
+pub fn lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)?.checked_abs()
+}
+
+pub fn gcd_all(nums: impl IntoIterator<Item = i128>) -> i128 {
+    nums.into_iter().fold(0, gcd)
+}
+
+/// Least common multiple of every number, 1 for none at all.
+pub fn lcm_all(nums: impl IntoIterator<Item = i128>) -> Option<i128> {
+    nums.into_iter().try_fold(1, lcm)
+}
+
+/// `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every pair at once, by the Chinese remainder theorem.
+///
+/// Returns the smallest non-negative `x` with the modulus it repeats over, or `None` if the
+/// congruences contradict each other or the answer overflows. The moduli don't have to be
+/// coprime, but must be positive.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, m), &(residue, modulus)| {
+            if modulus <= 0 {
+                return None;
+            }
+            let residue = residue.rem_euclid(modulus);
+            // x + m * k ≡ residue (mod modulus), so m * k ≡ residue - x
+            let (g, inverse, _) = extended_gcd(m, modulus);
+            let gap = residue - x;
+            if gap % g != 0 {
+                return None;
+            }
+            let step = modulus / g;
+            let k = mul_mod(gap / g, inverse, step)?;
+            let combined = lcm(m, modulus)?;
+            let x = x.checked_add(m.checked_mul(k)?)?.rem_euclid(combined);
+            Some((x, combined))
+        })
+}
+
+/// `a * b mod m`, for any signs of `a` and `b`, without overflowing on the way.
+fn mul_mod(a: i128, b: i128, m: i128) -> Option<i128> {
+    let (a, b) = (a.rem_euclid(m), b.rem_euclid(m));
+    match a.checked_mul(b) {
+        Some(product) => Some(product % m),
+        // double and add, which only needs values below 2m to fit
+        None => {
+            let (mut result, mut a, mut b) = (0i128, a, b);
+            while b > 0 {
+                if b & 1 == 1 {
+                    result = result.checked_add(a)? % m;
+                }
+                a = a.checked_add(a)? % m;
+                b >>= 1;
+            }
+            Some(result)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(12, 18, 6)]
+    #[case(-12, 18, 6)]
+    #[case(0, 5, 5)]
+    #[case(0, 0, 0)]
+    #[case(17, 5, 1)]
+    fn finds_gcd(#[case] a: i128, #[case] b: i128, #[case] expected: i128) {
+        assert_eq!(gcd(a, b), expected);
+    }
+
+    #[test]
+    fn finds_lcm_of_many() {
+        assert_eq!(lcm_all([5, 8, 12]), Some(120));
+        assert_eq!(
+            lcm_all([18023, 21251, 15871, 16409, 14257, 11567]),
+            Some(11678319315857)
+        );
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+    }
+
+    #[test]
+    fn reports_overflow() {
+        assert_eq!(lcm(i128::MAX, i128::MAX - 1), None);
+        assert_eq!(lcm_all([1 << 120, 3, 5, 7, 11, 13]), None);
+    }
+
+    #[rstest]
+    #[case(&[(2, 3), (3, 5), (2, 7)], Some((23, 105)))]
+    #[case(&[(1, 4), (3, 6)], Some((9, 12)))]
+    #[case(&[(1, 4), (2, 6)], None)]
+    #[case(&[(-1, 10)], Some((9, 10)))]
+    #[case(&[], Some((0, 1)))]
+    fn solves_congruences(
+        #[case] congruences: &[(i128, i128)],
+        #[case] expected: Option<(i128, i128)>,
+    ) {
+        assert_eq!(crt(congruences), expected);
+    }
+
+    #[test]
+    fn solves_congruences_near_the_limit() {
+        let (p, q) = (1_000_000_000_000_000_003, 1_000_000_000_000_000_009);
+        let (x, m) = crt(&[(5, p), (7, q)]).unwrap();
+        assert_eq!(m, p * q);
+        assert_eq!((x % p, x % q), (5, 7));
+    }
+
+    proptest! {
+        #[test]
+        fn extended_gcd_gives_bezout(a in -10_000i128..10_000, b in -10_000i128..10_000) {
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(g, gcd(a, b));
+            prop_assert_eq!(a * x + b * y, g);
+        }
+
+        #[test]
+        fn crt_agrees_with_search(
+            congruences in prop::collection::vec((0i128..50, 1i128..12), 1..4)
+        ) {
+            let modulus = lcm_all(congruences.iter().map(|&(_, m)| m)).unwrap();
+            let found = (0..modulus)
+                .find(|x| congruences.iter().all(|&(r, m)| x % m == r % m))
+                .map(|x| (x, modulus));
+            prop_assert_eq!(crt(&congruences), found);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt::Display;
+
+use crate::gcd;
+
+/// An exact fraction in lowest terms, with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    numer: i128,
+    denom: i128,
+}
+
+impl Ratio {
+    pub const ZERO: Ratio = Ratio { numer: 0, denom: 1 };
+
+    /// `numer / denom`, or `None` when dividing by zero.
+    pub fn new(numer: i128, denom: i128) -> Option<Self> {
+        if denom == 0 {
+            return None;
+        }
+        let g = gcd(numer, denom);
+        let sign = denom.signum();
+        Some(Self {
+            numer: sign.checked_mul(numer / g)?,
+            denom: sign.checked_mul(denom / g)?,
+        })
+    }
+
+    pub fn integer(n: i128) -> Self {
+        Self { numer: n, denom: 1 }
+    }
+
+    pub fn numer(self) -> i128 {
+        self.numer
+    }
+
+    pub fn denom(self) -> i128 {
+        self.denom
+    }
+
+    /// The value as a whole number, if it is one.
+    pub fn to_integer(self) -> Option<i128> {
+        (self.denom == 1).then_some(self.numer)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let denom = crate::lcm(self.denom, other.denom)?;
+        let numer = self
+            .numer
+            .checked_mul(denom / self.denom)?
+            .checked_add(other.numer.checked_mul(denom / other.denom)?)?;
+        Self::new(numer, denom)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(Self {
+            numer: other.numer.checked_neg()?,
+            ..other
+        })
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        // cancel across first, so the products stay as small as they can
+        let a = gcd(self.numer, other.denom).max(1);
+        let b = gcd(other.numer, self.denom).max(1);
+        Self::new(
+            (self.numer / a).checked_mul(other.numer / b)?,
+            (self.denom / b).checked_mul(other.denom / a)?,
+        )
+    }
+
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_mul(Self::new(other.denom, other.numer)?)
+    }
+}
+
+impl Display for Ratio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.denom {
+            1 => write!(f, "{}", self.numer),
+            _ => write!(f, "{}/{}", self.numer, self.denom),
+        }
+    }
+}
+
+/// A polynomial with exact coefficients, stored lowest power first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    coefficients: Vec<Ratio>,
+}
+
+impl Polynomial {
+    /// The lowest degree polynomial through every `(x, y)` point, or `None` if two points
+    /// share an `x` or the arithmetic overflows.
+    pub fn fit(points: &[(i128, i128)]) -> Option<Self> {
+        // Newton's divided differences, one column at a time
+        let xs = points.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+        let mut column = points
+            .iter()
+            .map(|&(_, y)| Ratio::integer(y))
+            .collect::<Vec<_>>();
+        let mut newton = Vec::with_capacity(points.len());
+        for width in 0..points.len() {
+            newton.push(column[0]);
+            column = (0..column.len() - 1)
+                .map(|i| {
+                    let rise = column[i + 1].checked_sub(column[i])?;
+                    let run = Ratio::integer(xs[i + width + 1].checked_sub(xs[i])?);
+                    rise.checked_div(run)
+                })
+                .collect::<Option<Vec<_>>>()?;
+        }
+
+        // expand c0 + (x - x0)(c1 + (x - x1)(c2 + ...)) from the innermost term out
+        let mut coefficients = Vec::<Ratio>::new();
+        for (i, &c) in newton.iter().enumerate().rev() {
+            // multiply by (x - xs[i]), then add c
+            let shift = Ratio::integer(xs[i]);
+            let mut next = vec![Ratio::ZERO; coefficients.len() + 1];
+            for (power, &a) in coefficients.iter().enumerate() {
+                next[power + 1] = next[power + 1].checked_add(a)?;
+                next[power] = next[power].checked_sub(a.checked_mul(shift)?)?;
+            }
+            next[0] = next[0].checked_add(c)?;
+            coefficients = next;
+        }
+        while coefficients.last() == Some(&Ratio::ZERO) {
+            coefficients.pop();
+        }
+        Some(Self { coefficients })
+    }
+
+    /// Coefficients from the constant term up; empty for the zero polynomial.
+    pub fn coefficients(&self) -> &[Ratio] {
+        &self.coefficients
+    }
+
+    /// Highest power with a non-zero coefficient, `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn eval(&self, x: i128) -> Option<Ratio> {
+        let x = Ratio::integer(x);
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(Ratio::ZERO, |acc, &c| acc.checked_mul(x)?.checked_add(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extrapolate;
+    use proptest::prelude::*;
+
+    fn ratios(pairs: &[(i128, i128)]) -> Vec<Ratio> {
+        pairs
+            .iter()
+            .map(|&(n, d)| Ratio::new(n, d).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn keeps_fractions_in_lowest_terms() {
+        let half = Ratio::new(-3, -6).unwrap();
+        assert_eq!((half.numer(), half.denom()), (1, 2));
+        assert_eq!(Ratio::new(4, -6).unwrap().to_string(), "-2/3");
+        assert_eq!(Ratio::new(1, 0), None);
+        let third = Ratio::new(1, 3).unwrap();
+        assert_eq!(half.checked_add(third), Ratio::new(5, 6));
+        assert_eq!(half.checked_div(third), Ratio::new(3, 2));
+    }
+
+    #[test]
+    fn fits_through_points() {
+        // 1/2 x^2 + 1/2 x, the triangle numbers
+        let poly = Polynomial::fit(&[(1, 1), (2, 3), (3, 6), (4, 10)]).unwrap();
+        assert_eq!(poly.coefficients(), ratios(&[(0, 1), (1, 2), (1, 2)]));
+        assert_eq!(poly.degree(), Some(2));
+        assert_eq!(poly.eval(100).and_then(Ratio::to_integer), Some(5050));
+        assert_eq!(poly.eval(-1).and_then(Ratio::to_integer), Some(0));
+    }
+
+    #[test]
+    fn rejects_repeated_x() {
+        assert_eq!(Polynomial::fit(&[(1, 1), (1, 2)]), None);
+        assert_eq!(Polynomial::fit(&[]).unwrap().degree(), None);
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_finite_differences(
+            samples in prop::collection::vec(-1000i128..1000, 1..6),
+            index in -50i128..50,
+        ) {
+            let points = samples
+                .iter()
+                .enumerate()
+                .map(|(x, &y)| (x as i128, y))
+                .collect::<Vec<_>>();
+            let poly = Polynomial::fit(&points).unwrap();
+            prop_assert_eq!(
+                poly.eval(index).and_then(Ratio::to_integer),
+                extrapolate(&samples, index)
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+/// Differences between each value and the one after it, or `None` if one overflows.
+pub fn differences(values: &[i128]) -> Option<Vec<i128>> {
+    values
+        .windows(2)
+        .map(|pair| pair[1].checked_sub(pair[0]))
+        .collect()
+}
+
+/// The value at `index` of the polynomial sequence that starts with `samples`.
+///
+/// `samples[0]` is index 0, so `samples.len()` is the next value and `-1` the one before.
+/// The sequence is the lowest degree polynomial through every sample, found by taking
+/// differences until they are all zero. `None` if there are no samples or the value overflows.
+pub fn extrapolate(samples: &[i128], index: i128) -> Option<i128> {
+    // Newton's forward formula: the sum over k of C(index, k) times the k-th difference at 0
+    let mut row = samples.to_vec();
+    let mut value = 0i128;
+    let mut choose = 1i128;
+    let mut k = 0;
+    while !row.iter().all(|&d| d == 0) {
+        value = value.checked_add(choose.checked_mul(row[0])?)?;
+        k += 1;
+        // C(n, k) = C(n, k - 1) * (n - k + 1) / k, which always divides exactly
+        choose = choose.checked_mul(index.checked_sub(k - 1)?)? / k;
+        row = differences(&row)?;
+    }
+    (!samples.is_empty()).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(&[0, 3, 6, 9, 12, 15], 18, -3)]
+    #[case(&[1, 3, 6, 10, 15, 21], 28, 0)]
+    #[case(&[10, 13, 16, 21, 30, 45], 68, 5)]
+    fn extends_both_ways(#[case] samples: &[i128], #[case] next: i128, #[case] before: i128) {
+        assert_eq!(extrapolate(samples, samples.len() as i128), Some(next));
+        assert_eq!(extrapolate(samples, -1), Some(before));
+    }
+
+    #[test]
+    fn reaches_far_indices() {
+        // squares, from three samples
+        assert_eq!(extrapolate(&[0, 1, 4], 1_000_000), Some(1_000_000_000_000));
+        assert_eq!(extrapolate(&[0, 1, 4], -7), Some(49));
+        // the quadratic a day 21 garden grows by
+        assert_eq!(
+            extrapolate(&[3906, 34896, 96784], 202300),
+            Some(632_257_949_158_206)
+        );
+    }
+
+    #[test]
+    fn handles_edge_cases() {
+        assert_eq!(extrapolate(&[], 3), None);
+        assert_eq!(extrapolate(&[0, 0], 5), Some(0));
+        assert_eq!(extrapolate(&[7], -2), Some(7));
+        assert_eq!(extrapolate(&[0, 1, 1 << 100], 1 << 100), None);
+        assert_eq!(differences(&[1, 4, 9]), Some(vec![3, 5]));
+    }
+
+    #[test]
+    fn reports_overflowing_differences() {
+        assert_eq!(differences(&[i128::MIN, i128::MAX]), None);
+        assert_eq!(differences(&[-1, i128::MAX]), None);
+        assert_eq!(differences(&[0, i128::MAX]), Some(vec![i128::MAX]));
+        assert_eq!(extrapolate(&[i128::MIN, 0, i128::MAX], 3), None);
+        assert_eq!(extrapolate(&[0, 1], i128::MIN), None);
+    }
+}
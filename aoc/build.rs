@@ -0,0 +1,5 @@
+fn main() {
+    // the example tests are generated from this directory's contents, so adding a file
+    // has to rebuild the crate
+    println!("cargo:rerun-if-changed=../examples");
+}
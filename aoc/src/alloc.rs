@@ -0,0 +1,124 @@
+#[cfg(feature = "count-allocs")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+/// Whether the runner was built with `--features count-allocs`, so that [`measure`] counts.
+pub const ENABLED: bool = cfg!(feature = "count-allocs");
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, keeping a tally of what passes through it.
+#[cfg(feature = "count-allocs")]
+pub struct Counting;
+
+#[cfg(feature = "count-allocs")]
+impl Counting {
+    fn grew(size: usize) {
+        let size = size as u64;
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn shrank(size: usize) {
+        CURRENT.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "count-allocs")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::shrank(layout.size());
+    }
+
+    // a reallocation counts as a fresh allocation of the new size, as that's the cost of growing
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            Self::shrank(layout.size());
+            Self::grew(new_size);
+        }
+        new
+    }
+}
+
+/// What one piece of work asked of the heap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocs {
+    pub count: u64,
+    pub bytes: u64,
+    /// Most heap held at once, above what was already held when the work started.
+    pub peak_bytes: u64,
+}
+
+/// Runs `f`, counting its allocations if the counting allocator is installed.
+///
+/// The counters are shared by every thread, so only measure one thing at a time.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    let count = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let held = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(held, Ordering::Relaxed);
+
+    let result = f();
+
+    let allocs = Allocs {
+        count: ALLOCATIONS.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(held),
+    };
+    (result, Some(allocs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[test]
+    fn counts_only_when_installed() {
+        let (_, allocs) = measure(|| {
+            drop(black_box(vec![0u8; 4096]));
+            drop(black_box(vec![0u8; 4096]));
+        });
+
+        assert_eq!(allocs.is_some(), ENABLED);
+        // other tests allocate alongside this one, so the counts are lower bounds
+        if let Some(allocs) = allocs {
+            assert!(allocs.count >= 2);
+            assert!(allocs.bytes >= 8192);
+            assert!(allocs.peak_bytes >= 4096);
+        }
+    }
+}
@@ -0,0 +1,428 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::input;
+
+/// Manifest key for the real puzzle input; every other key is an example name for `--input`.
+pub const REAL: &str = "real";
+
+/// An expected answer, written in the manifest as a number or a string.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Why the site turned an answer down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Reason {
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Reason {
+    /// How the reason is spelled in `answers.toml`.
+    fn key(self) -> &'static str {
+        match self {
+            Reason::Wrong => "wrong",
+            Reason::TooHigh => "too-high",
+            Reason::TooLow => "too-low",
+        }
+    }
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Reason::Wrong => "wrong",
+            Reason::TooHigh => "too high",
+            Reason::TooLow => "too low",
+        })
+    }
+}
+
+/// An answer that `aoc submit` was told is wrong, so it is never sent again.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rejection {
+    pub answer: Answer,
+    pub reason: Reason,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Rejected {
+    #[serde(default)]
+    part1: Vec<Rejection>,
+    #[serde(default)]
+    part2: Vec<Rejection>,
+}
+
+/// Expected answers for one input; a part is left out when its answer isn't known.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+    #[serde(default)]
+    rejected: Rejected,
+}
+
+impl Expected {
+    /// Sidecar file beside an example, `examples/NN/<name>.toml`.
+    pub fn path(day: u8, example: &str) -> PathBuf {
+        input::examples_dir(day).join(format!("{example}.toml"))
+    }
+
+    /// Reads a sidecar file; every example needs one, so a missing file is an error.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = input::read(path)?;
+        toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn rejected(&self, part: u8) -> &[Rejection] {
+        match part {
+            1 => &self.rejected.part1,
+            2 => &self.rejected.part2,
+            _ => &[],
+        }
+    }
+}
+
+/// Expected answers for every input of a day, keyed by input name.
+///
+/// The real input's answers live in the day's `answers.toml`:
+///
+/// ```toml
+/// [real]
+/// part1 = 54304
+/// part2 = 54418
+/// ```
+///
+/// along with anything `aoc submit` was told is wrong:
+///
+/// ```toml
+/// [real.rejected]
+/// part2 = [{ answer = 60000, reason = "too-high" }]
+/// ```
+///
+/// and each example's in the sidecar beside it, such as `examples/01/example-1.toml`:
+///
+/// ```toml
+/// part1 = 142
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Manifest {
+    inputs: BTreeMap<String, Expected>,
+}
+
+impl Manifest {
+    pub fn path(day: u8) -> PathBuf {
+        input::day_dir(day).join("answers.toml")
+    }
+
+    /// Reads the answers for `day`, treating a missing `answers.toml` as having none.
+    pub fn load(day: u8) -> Result<Self, String> {
+        let path = Self::path(day);
+        let mut manifest = if path.is_file() {
+            Self::parse(&input::read(&path)?).map_err(|e| format!("{}: {e}", path.display()))?
+        } else {
+            Self::default()
+        };
+        for name in input::examples(day)? {
+            let expected = Expected::load(&Expected::path(day, &name))?;
+            manifest.inputs.insert(name, expected);
+        }
+        Ok(manifest)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// Input names with at least one expected answer, the real input first.
+    pub fn inputs(&self) -> impl Iterator<Item = &str> {
+        let real = self.inputs.contains_key(REAL).then_some(REAL);
+        real.into_iter().chain(
+            self.inputs
+                .keys()
+                .map(String::as_str)
+                .filter(|name| *name != REAL),
+        )
+    }
+
+    pub fn expected(&self, input: &str, part: u8) -> Option<&Answer> {
+        self.inputs.get(input).and_then(|e| e.part(part))
+    }
+
+    /// Why `answer` shouldn't be submitted for the real input, if what's known already rules it out.
+    pub fn screen(&self, part: u8, answer: &str) -> Result<(), String> {
+        if let Some(right) = self.expected(REAL, part) {
+            return Err(match right.to_string() == answer {
+                true => format!("{answer} is already known to be right"),
+                false => format!("the right answer is already known to be {right}"),
+            });
+        }
+
+        let rejected = self.inputs.get(REAL).map_or(&[][..], |e| e.rejected(part));
+        if let Some(seen) = rejected.iter().find(|r| r.answer.to_string() == answer) {
+            return Err(format!("{answer} was already rejected as {}", seen.reason));
+        }
+
+        // too high and too low answers bound the right one, so only numbers between them can be right
+        let Ok(number) = answer.parse::<i64>() else {
+            return Ok(());
+        };
+        for rejection in rejected {
+            let Answer::Number(bound) = rejection.answer else {
+                continue;
+            };
+            let out = match rejection.reason {
+                Reason::TooHigh => number >= bound,
+                Reason::TooLow => number <= bound,
+                Reason::Wrong => false,
+            };
+            if out {
+                return Err(format!(
+                    "{answer} can't be right, as {bound} was {}",
+                    rejection.reason
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the site's verdict on an answer for the real input into the day's `answers.toml`.
+pub fn record(day: u8, part: u8, answer: &str, verdict: Result<(), Reason>) -> Result<(), String> {
+    let path = Manifest::path(day);
+    let text = if path.is_file() {
+        input::read(&path)?
+    } else {
+        String::new()
+    };
+    let text =
+        recorded(&text, part, answer, verdict).map_err(|e| format!("{}: {e}", path.display()))?;
+    std::fs::write(&path, text).map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+/// `text` with the verdict added, keeping the rest of the file as it was written.
+fn recorded(
+    text: &str,
+    part: u8,
+    answer: &str,
+    verdict: Result<(), Reason>,
+) -> Result<String, String> {
+    use toml_edit::{value, Array, DocumentMut, InlineTable, Item, Table, Value};
+
+    let mut doc = text.parse::<DocumentMut>().map_err(|e| e.to_string())?;
+    let answer = match answer.parse::<i64>() {
+        Ok(n) => Value::from(n),
+        Err(_) => Value::from(answer),
+    };
+    let key = format!("part{part}");
+
+    let real = doc
+        .entry(REAL)
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or("[real] is not a table")?;
+    match verdict {
+        Ok(()) => {
+            real.insert(&key, value(answer));
+        }
+        Err(reason) => {
+            let rejected = real
+                .entry("rejected")
+                .or_insert_with(|| Item::Table(Table::new()))
+                .as_table_mut()
+                .ok_or("[real.rejected] is not a table")?;
+            let list = rejected
+                .entry(&key)
+                .or_insert_with(|| value(Array::new()))
+                .as_array_mut()
+                .ok_or_else(|| format!("[real.rejected] {key} is not a list"))?;
+
+            let mut rejection = InlineTable::new();
+            rejection.insert("answer", answer);
+            rejection.insert("reason", Value::from(reason.key()));
+            list.push(rejection);
+        }
+    }
+    Ok(doc.to_string())
+}
+
+/// Result of comparing an answer with the expected one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail(String),
+}
+
+impl Outcome {
+    pub fn check(expected: &Answer, actual: &str) -> Self {
+        let expected = expected.to_string();
+        if expected == actual {
+            Outcome::Pass
+        } else {
+            Outcome::Fail(format!("expected {expected}, got {actual}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use rstest::rstest;
+
+    const MANIFEST: &str = r#"
+[test]
+part1 = 142
+
+[real]
+part1 = 54304
+part2 = "hello"
+"#;
+
+    #[test]
+    fn reads_numbers_and_strings() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+        assert_eq!(manifest.expected(REAL, 1), Some(&Answer::Number(54304)));
+        assert_eq!(
+            manifest.expected(REAL, 2),
+            Some(&Answer::Text("hello".to_string()))
+        );
+        assert_eq!(manifest.expected("test", 2), None);
+        assert_eq!(manifest.inputs().collect::<Vec<_>>(), vec![REAL, "test"]);
+    }
+
+    #[test]
+    fn rejects_unknown_parts() {
+        assert!(Manifest::parse("[real]\npart3 = 1").is_err());
+    }
+
+    #[test]
+    fn compares_rendered_answers() {
+        assert_eq!(Outcome::check(&Answer::Number(42), "42"), Outcome::Pass);
+        assert_eq!(
+            Outcome::check(&Answer::Number(42), "41"),
+            Outcome::Fail("expected 42, got 41".to_string())
+        );
+    }
+
+    #[test]
+    fn screens_answers_known_to_be_wrong() {
+        let manifest = Manifest::parse(
+            r#"
+[real]
+part1 = 12
+
+[real.rejected]
+part2 = [
+    { answer = 500, reason = "too-high" },
+    { answer = 100, reason = "too-low" },
+    { answer = 300, reason = "wrong" },
+]
+"#,
+        )
+        .unwrap();
+
+        assert!(manifest
+            .screen(1, "12")
+            .unwrap_err()
+            .contains("already known"));
+        assert!(manifest.screen(1, "13").is_err());
+        assert_eq!(
+            manifest.screen(2, "300"),
+            Err("300 was already rejected as wrong".to_string())
+        );
+        assert_eq!(
+            manifest.screen(2, "600"),
+            Err("600 can't be right, as 500 was too high".to_string())
+        );
+        assert!(manifest.screen(2, "100").is_err());
+        assert_eq!(manifest.screen(2, "250"), Ok(()));
+        assert_eq!(manifest.screen(2, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn records_verdicts_beside_what_was_written() {
+        let text = "# checked by hand\n[real]\npart1 = 12\n";
+        let text = recorded(text, 2, "500", Err(Reason::TooHigh)).unwrap();
+        let text = recorded(&text, 2, "abc", Err(Reason::Wrong)).unwrap();
+        let text = recorded(&text, 2, "250", Ok(())).unwrap();
+        assert_eq!(
+            text,
+            r#"# checked by hand
+[real]
+part1 = 12
+part2 = 250
+
+[real.rejected]
+part2 = [{ answer = 500, reason = "too-high" }, { answer = "abc", reason = "wrong" }]
+"#
+        );
+
+        let manifest = Manifest::parse(&text).unwrap();
+        assert_eq!(manifest.expected(REAL, 2), Some(&Answer::Number(250)));
+        assert_eq!(manifest.inputs.get(REAL).unwrap().rejected(2).len(), 2);
+        assert_eq!(recorded("", 1, "7", Ok(())).unwrap(), "[real]\npart1 = 7\n");
+    }
+
+    /// One case per example file, checked against its sidecar.
+    #[rstest]
+    fn example_matches_sidecar(#[files("../examples/*/*.txt")] path: PathBuf) {
+        let day = path
+            .parent()
+            .and_then(Path::file_name)
+            .and_then(|dir| dir.to_str())
+            .and_then(|dir| dir.parse::<u8>().ok())
+            .expect("examples live in examples/NN/");
+        let expected = Expected::load(&path.with_extension("toml")).unwrap();
+        let input = input::read(&path).unwrap();
+
+        let mut checked = 0;
+        for part in [1, 2] {
+            let Some(answer) = expected.part(part) else {
+                continue;
+            };
+            let actual = days::run(day, part, &input)
+                .expect("day is solved")
+                .unwrap_or_else(|e| panic!("{e}"));
+            assert_eq!(
+                Outcome::check(answer, &actual),
+                Outcome::Pass,
+                "day {day} part {part}"
+            );
+            checked += 1;
+        }
+        assert!(checked > 0, "{} has no expected answers", path.display());
+    }
+}
@@ -0,0 +1,266 @@
+use std::{fmt::Write, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{alloc::Allocs, days::Timing};
+
+/// Spread of a set of timings, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    /// Summarises `samples`, which must not be empty.
+    pub fn new(samples: impl IntoIterator<Item = Duration>) -> Self {
+        let mut nanos = samples
+            .into_iter()
+            .map(|d| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX))
+            .collect::<Vec<_>>();
+        nanos.sort_unstable();
+        Self {
+            min_ns: nanos[0],
+            median_ns: nanos[nanos.len() / 2],
+            max_ns: nanos[nanos.len() - 1],
+        }
+    }
+}
+
+/// Timings for one part of one day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub parse: Stats,
+    pub solve: Stats,
+    /// Allocations made by the first run, when the runner was built to count them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocs: Option<Allocs>,
+    /// Relative change in median parse plus solve time against the baseline, `0.1` being 10% slower.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change: Option<f64>,
+}
+
+impl Row {
+    pub fn new(day: u8, part: u8, timings: &[Timing]) -> Self {
+        Self {
+            day,
+            part,
+            parse: Stats::new(timings.iter().map(|t| t.parse)),
+            solve: Stats::new(timings.iter().map(|t| t.solve)),
+            allocs: timings[0].allocs,
+            change: None,
+        }
+    }
+
+    fn median_total(&self) -> u64 {
+        self.parse.median_ns + self.solve.median_ns
+    }
+}
+
+/// A full benchmark run, as written by `--format json` and read back by `--baseline`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: usize,
+    pub results: Vec<Row>,
+}
+
+impl Report {
+    /// Fills in each row's change against the same day and part in `baseline`.
+    pub fn compare(&mut self, baseline: &Report) {
+        for row in &mut self.results {
+            row.change = baseline
+                .results
+                .iter()
+                .find(|old| old.day == row.day && old.part == row.part)
+                .filter(|old| old.median_total() > 0)
+                .map(|old| row.median_total() as f64 / old.median_total() as f64 - 1.0);
+        }
+    }
+
+    /// Rows that got slower than the baseline by more than `tolerance`.
+    pub fn regressions(&self, tolerance: f64) -> impl Iterator<Item = &Row> {
+        self.results
+            .iter()
+            .filter(move |row| row.change.is_some_and(|change| change > tolerance))
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let compared = self.results.iter().any(|row| row.change.is_some());
+        let counted = self.results.iter().any(|row| row.allocs.is_some());
+
+        let mut out = String::from(
+            "| Day | Part | Parse min | Parse median | Parse max | Solve min | Solve median | Solve max |",
+        );
+        if counted {
+            out.push_str(" Allocs | Allocated | Peak heap |");
+        }
+        if compared {
+            out.push_str(" vs baseline |");
+        }
+        out.push_str("\n|---:|---:|---:|---:|---:|---:|---:|---:|");
+        if counted {
+            out.push_str("---:|---:|---:|");
+        }
+        if compared {
+            out.push_str("---:|");
+        }
+        out.push('\n');
+
+        for row in &self.results {
+            write!(out, "| {} | {} |", row.day, row.part).unwrap();
+            for stats in [row.parse, row.solve] {
+                for ns in [stats.min_ns, stats.median_ns, stats.max_ns] {
+                    write!(out, " {:.2?} |", Duration::from_nanos(ns)).unwrap();
+                }
+            }
+            if counted {
+                match row.allocs {
+                    Some(allocs) => write!(
+                        out,
+                        " {} | {} | {} |",
+                        allocs.count,
+                        bytes(allocs.bytes),
+                        bytes(allocs.peak_bytes)
+                    )
+                    .unwrap(),
+                    None => out.push_str(" - | - | - |"),
+                }
+            }
+            if compared {
+                match row.change {
+                    Some(change) => write!(out, " {:+.1}% |", change * 100.0).unwrap(),
+                    None => out.push_str(" - |"),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report only holds plain data")
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        serde_json::from_str(text).map_err(|e| format!("not a benchmark report: {e}"))
+    }
+}
+
+/// A byte count in the largest binary unit that keeps it at one or more.
+fn bytes(n: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut size = n as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{n}B"),
+        _ => format!("{size:.1}{}", units[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(day: u8, parse_ns: u64, solve_ns: u64) -> Row {
+        let stats = |ns| Stats {
+            min_ns: ns,
+            median_ns: ns,
+            max_ns: ns,
+        };
+        Row {
+            day,
+            part: 1,
+            parse: stats(parse_ns),
+            solve: stats(solve_ns),
+            allocs: None,
+            change: None,
+        }
+    }
+
+    #[test]
+    fn summarises_samples() {
+        let stats = Stats::new([5, 1, 3].map(Duration::from_nanos));
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 1,
+                median_ns: 3,
+                max_ns: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn flags_regressions_against_baseline() {
+        let baseline = Report {
+            runs: 1,
+            results: vec![row(1, 100, 900), row(2, 100, 900)],
+        };
+        let mut report = Report {
+            runs: 1,
+            results: vec![row(1, 100, 1400), row(2, 100, 400), row(3, 1, 1)],
+        };
+        report.compare(&baseline);
+
+        let changes = report.results.iter().map(|r| r.change).collect::<Vec<_>>();
+        assert_eq!(changes, vec![Some(0.5), Some(-0.5), None]);
+        let slow = report.regressions(0.1).map(|r| r.day).collect::<Vec<_>>();
+        assert_eq!(slow, vec![1]);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let report = Report {
+            runs: 3,
+            results: vec![row(4, 10, 20)],
+        };
+        assert_eq!(Report::from_json(&report.to_json()), Ok(report));
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let mut report = Report {
+            runs: 1,
+            results: vec![row(1, 1_500, 2_000_000)],
+        };
+        assert_eq!(
+            report.to_markdown().lines().nth(2),
+            Some("| 1 | 1 | 1.50µs | 1.50µs | 1.50µs | 2.00ms | 2.00ms | 2.00ms |")
+        );
+
+        report.results[0].change = Some(-0.25);
+        assert!(report
+            .to_markdown()
+            .lines()
+            .nth(2)
+            .unwrap()
+            .ends_with(" -25.0% |"));
+    }
+
+    #[test]
+    fn renders_allocations_when_counted() {
+        let mut report = Report {
+            runs: 1,
+            results: vec![row(1, 1, 1), row(2, 1, 1)],
+        };
+        report.results[0].allocs = Some(Allocs {
+            count: 12,
+            bytes: 3 * 1024 * 1024,
+            peak_bytes: 512,
+        });
+
+        let table = report.to_markdown();
+        let lines = table.lines().collect::<Vec<_>>();
+        assert!(lines[0].ends_with(" Allocs | Allocated | Peak heap |"));
+        assert!(lines[2].ends_with(" 12 | 3.0MiB | 512B |"));
+        assert!(lines[3].ends_with(" - | - | - |"));
+        assert_eq!(Report::from_json(&report.to_json()), Ok(report));
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    hint::black_box,
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Animate, Generate, Image, ParseError, Solution, Step, Unsolvable};
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::alloc::{self, Allocs};
+
+/// Days that have a solution wired into the runner.
+pub const SOLVED: RangeInclusive<u8> = 1..=21;
+
+/// Days that can draw their solution as a sequence of frames.
+pub const ANIMATED: [u8; 5] = [10, 14, 16, 17, 21];
+
+/// Days with a simulation that can be stepped through.
+pub const SIMULATED: [u8; 4] = [14, 16, 20, 21];
+
+/// Something to do with a day's `Solution`, once the day number has picked the type.
+trait Visit {
+    type Output;
+
+    fn visit<S: Solution>(self) -> Self::Output;
+}
+
+/// Calls `visitor` with the solution for `day`, or returns `None` if there is none.
+fn with_day<V: Visit>(day: u8, visitor: V) -> Option<V::Output> {
+    Some(match day {
+        1 => visitor.visit::<day_01::Day01>(),
+        2 => visitor.visit::<day_02::Day02>(),
+        3 => visitor.visit::<day_03::Day03>(),
+        4 => visitor.visit::<day_04::Day04>(),
+        5 => visitor.visit::<day_05::Day05>(),
+        6 => visitor.visit::<day_06::Day06>(),
+        7 => visitor.visit::<day_07::Day07>(),
+        8 => visitor.visit::<day_08::Day08>(),
+        9 => visitor.visit::<day_09::Day09>(),
+        10 => visitor.visit::<day_10::Day10>(),
+        11 => visitor.visit::<day_11::Day11>(),
+        12 => visitor.visit::<day_12::Day12>(),
+        13 => visitor.visit::<day_13::Day13>(),
+        14 => visitor.visit::<day_14::Day14>(),
+        15 => visitor.visit::<day_15::Day15>(),
+        16 => visitor.visit::<day_16::Day16>(),
+        17 => visitor.visit::<day_17::Day17>(),
+        18 => visitor.visit::<day_18::Day18>(),
+        19 => visitor.visit::<day_19::Day19>(),
+        20 => visitor.visit::<day_20::Day20>(),
+        21 => visitor.visit::<day_21::Day21>(),
+        _ => return None,
+    })
+}
+
+/// How long one run of a solution spent parsing and solving.
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+    /// Allocations made parsing and solving, when the runner counts them.
+    pub allocs: Option<Allocs>,
+}
+
+/// The rendered answer from one run of a solution, and how long it took.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answer: String,
+    pub timing: Timing,
+}
+
+/// Why a part gave no answer: the input didn't parse, or it parsed but has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Parse(ParseError),
+    Unsolvable(Unsolvable),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Parse(e) => e.fmt(f),
+            Failure::Unsolvable(e) => e.fmt(f),
+        }
+    }
+}
+
+/// Runs one part of a solution against `input`, timing the parse and solve steps.
+struct Solve<'a> {
+    part: u8,
+    input: &'a str,
+}
+
+impl Visit for Solve<'_> {
+    type Output = Option<Result<Solved, Failure>>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let start = Instant::now();
+        let parsed = match S::parse(self.input) {
+            Ok(parsed) => parsed,
+            Err(e) => return Some(Err(Failure::Parse(e))),
+        };
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match self.part {
+            1 => S::part1(&parsed).map(|answer| answer.to_string()),
+            2 => S::part2(&parsed).map(|answer| answer.to_string()),
+            _ => return None,
+        };
+        let solve = start.elapsed();
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(e) => return Some(Err(Failure::Unsolvable(e))),
+        };
+
+        Some(Ok(Solved {
+            answer,
+            timing: Timing {
+                parse,
+                solve,
+                allocs: None,
+            },
+        }))
+    }
+}
+
+/// Dispatches to the solver for `day`, or `None` if the day or part does not exist.
+pub fn solve(day: u8, part: u8, input: &str) -> Option<Result<Solved, Failure>> {
+    with_day(day, Solve { part, input }).flatten()
+}
+
+/// Like [`solve`], keeping only the answer.
+pub fn run(day: u8, part: u8, input: &str) -> Option<Result<String, Failure>> {
+    solve(day, part, input).map(|solved| solved.map(|s| s.answer))
+}
+
+/// Parses and solves one part once, timing each step.
+struct Time<'a> {
+    part: u8,
+    input: &'a str,
+}
+
+impl Visit for Time<'_> {
+    type Output = Option<Result<Timing, Failure>>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let (timing, allocs) = alloc::measure(|| {
+            let start = Instant::now();
+            let parsed = match S::parse(black_box(self.input)) {
+                Ok(parsed) => parsed,
+                Err(e) => return Some(Err(Failure::Parse(e))),
+            };
+            let parse = start.elapsed();
+
+            let start = Instant::now();
+            let solved = match self.part {
+                1 => black_box(S::part1(&parsed)).map(drop),
+                2 => black_box(S::part2(&parsed)).map(drop),
+                _ => return None,
+            };
+            let solve = start.elapsed();
+            if let Err(e) = solved {
+                return Some(Err(Failure::Unsolvable(e)));
+            }
+
+            Some(Ok(Timing {
+                parse,
+                solve,
+                allocs: None,
+            }))
+        });
+        timing.map(|timing| timing.map(|timing| Timing { allocs, ..timing }))
+    }
+}
+
+/// Times a single parse and solve of one part, like [`run`] but discarding the answer.
+pub fn time(day: u8, part: u8, input: &str) -> Option<Result<Timing, Failure>> {
+    with_day(day, Time { part, input }).flatten()
+}
+
+fn animate<A: Animate>(input: &str) -> Result<Vec<Image>, ParseError> {
+    A::parse(input).map(|parsed| A::frames(&parsed))
+}
+
+/// Draws every frame of the animation for `day`, or `None` if it has none.
+pub fn frames(day: u8, input: &str) -> Option<Result<Vec<Image>, ParseError>> {
+    Some(match day {
+        10 => animate::<day_10::Day10>(input),
+        14 => animate::<day_14::Day14>(input),
+        16 => animate::<day_16::Day16>(input),
+        17 => animate::<day_17::Day17>(input),
+        21 => animate::<day_21::Day21>(input),
+        _ => return None,
+    })
+}
+
+fn generated<G: Generate>(seed: u64, size: usize) -> String {
+    G::generate(&mut StdRng::seed_from_u64(seed), size)
+}
+
+/// Makes up an input for `day` from `seed`, or returns `None` if the day isn't solved.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    Some(match day {
+        1 => generated::<day_01::Day01>(seed, size),
+        2 => generated::<day_02::Day02>(seed, size),
+        3 => generated::<day_03::Day03>(seed, size),
+        4 => generated::<day_04::Day04>(seed, size),
+        5 => generated::<day_05::Day05>(seed, size),
+        6 => generated::<day_06::Day06>(seed, size),
+        7 => generated::<day_07::Day07>(seed, size),
+        8 => generated::<day_08::Day08>(seed, size),
+        9 => generated::<day_09::Day09>(seed, size),
+        10 => generated::<day_10::Day10>(seed, size),
+        11 => generated::<day_11::Day11>(seed, size),
+        12 => generated::<day_12::Day12>(seed, size),
+        13 => generated::<day_13::Day13>(seed, size),
+        14 => generated::<day_14::Day14>(seed, size),
+        15 => generated::<day_15::Day15>(seed, size),
+        16 => generated::<day_16::Day16>(seed, size),
+        17 => generated::<day_17::Day17>(seed, size),
+        18 => generated::<day_18::Day18>(seed, size),
+        19 => generated::<day_19::Day19>(seed, size),
+        20 => generated::<day_20::Day20>(seed, size),
+        21 => generated::<day_21::Day21>(seed, size),
+        _ => return None,
+    })
+}
+
+/// Something to do with a day's simulation, once the day number has picked its type.
+pub trait Drive {
+    type Output;
+
+    fn drive<S: Step>(self, start: S) -> Self::Output;
+}
+
+/// Calls `driver` with the simulation for `day`, or returns `None` if it has none.
+pub fn simulate<D: Drive>(
+    day: u8,
+    input: &str,
+    driver: D,
+) -> Option<Result<D::Output, ParseError>> {
+    Some(match day {
+        14 => day_14::parse(input).map(|board| driver.drive(day_14::step::SpinCycle::new(&board))),
+        16 => day_16::parse(input)
+            .map(|contraption| driver.drive(day_16::step::Beams::new(&contraption))),
+        20 => day_20::parse(input).map(|relays| driver.drive(day_20::step::Presses::new(&relays))),
+        21 => day_21::parse(input).map(|garden| driver.drive(day_21::step::Walk::new(&garden))),
+        _ => return None,
+    })
+}
@@ -0,0 +1,51 @@
+use aoc_core::ParseError;
+
+use crate::days::Failure;
+
+/// Renders a parse error with the offending line and a caret under the column.
+pub fn render(err: &ParseError, input: &str, origin: &str) -> String {
+    let text = input.lines().nth(err.line - 1).unwrap_or("");
+    let number = err.line.to_string();
+    let gutter = " ".repeat(number.len());
+    let caret = " ".repeat(err.column - 1);
+
+    format!("{err}\n{gutter}--> {origin}\n{gutter} |\n{number} | {text}\n{gutter} | {caret}^")
+}
+
+/// Explains why a part went unanswered, pointing into the input when it didn't parse.
+pub fn explain(failure: &Failure, input: &str, origin: &str) -> String {
+    match failure {
+        Failure::Parse(err) => render(err, input, origin),
+        Failure::Unsolvable(why) => format!("{why}\n --> {origin}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Unsolvable;
+
+    use super::*;
+
+    #[test]
+    fn points_at_column() {
+        let err = ParseError::new(4, 2, 9, "\"|\"");
+        let rendered = render(&err, "Card 1: 1 | 2\nCard 2: 3 4", "input.txt");
+        assert_eq!(
+            rendered,
+            "day 04, line 2, column 9: expected \"|\"
+ --> input.txt
+  |
+2 | Card 2: 3 4
+  |         ^"
+        );
+    }
+
+    #[test]
+    fn explains_unsolvable_input() {
+        let why = Failure::Unsolvable(Unsolvable::new(20, "nothing sends to rx"));
+        assert_eq!(
+            explain(&why, "broadcaster -> a", "input.txt"),
+            "day 20: no answer, nothing sends to rx\n --> input.txt"
+        );
+    }
+}
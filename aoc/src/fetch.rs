@@ -0,0 +1,342 @@
+use std::{
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::input;
+
+/// Environment variable holding the `session` cookie from a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2023;
+const USER_AGENT: &str = "github.com/avdwio/aoc-2023-rust";
+
+/// Least time between two requests to the server, counted across runs.
+pub const THROTTLE: Duration = Duration::from_secs(5);
+
+/// Where fetched inputs live, kept out of git by `.gitignore`.
+pub fn cache_dir() -> PathBuf {
+    input::workspace_dir().join(".cache")
+}
+
+/// Talks to the Advent of Code site as one logged in user.
+pub struct Client {
+    base: String,
+    session: String,
+    cache: PathBuf,
+    throttle: Duration,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(
+        base: impl Into<String>,
+        session: impl Into<String>,
+        cache: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            base: base.into(),
+            session: session.into(),
+            cache: cache.into(),
+            throttle: THROTTLE,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// A client for the real site, with the session token taken from [`SESSION_VAR`].
+    pub fn from_env() -> Result<Self, String> {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+            .ok_or_else(|| {
+                format!("set {SESSION_VAR} to the session cookie from adventofcode.com")
+            })?;
+        Ok(Self::new(BASE_URL, session, cache_dir()))
+    }
+
+    #[cfg(test)]
+    pub fn throttled(self, throttle: Duration) -> Self {
+        Self { throttle, ..self }
+    }
+
+    /// Where this user's input for `day` is kept once fetched.
+    ///
+    /// Inputs differ between accounts, so each session token gets its own directory.
+    pub fn cached(&self, day: u8) -> PathBuf {
+        self.cache
+            .join("inputs")
+            .join(account(&self.session))
+            .join(format!("day-{day:02}.txt"))
+    }
+
+    /// Downloads the input for `day` into the cache, unless it is already there.
+    pub fn fetch(&self, day: u8) -> Result<PathBuf, String> {
+        let path = self.cached(day);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let input = self.get(
+            &self.url(day, "input"),
+            &format!("could not fetch day {day}'s input"),
+        )?;
+
+        write(&path, &input)?;
+        Ok(path)
+    }
+
+    /// A page of a day's puzzle on the site, such as `input`.
+    pub(crate) fn url(&self, day: u8, page: &str) -> String {
+        format!("{}/{YEAR}/day/{day}/{page}", self.base)
+    }
+
+    fn get(&self, url: &str, what: &str) -> Result<String, String> {
+        self.wait();
+        let request = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session));
+        read(request.call(), what)
+    }
+
+    /// Sends a form as the logged in user, returning the page that comes back.
+    pub(crate) fn post(
+        &self,
+        url: &str,
+        form: &[(&str, &str)],
+        what: &str,
+    ) -> Result<String, String> {
+        self.wait();
+        let request = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={}", self.session));
+        read(request.send_form(form), what)
+    }
+
+    /// Sleeps until the throttle allows another request, then claims the slot.
+    fn wait(&self) {
+        let stamp = self.cache.join("last-request");
+        let last = std::fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(last) = last {
+            let since = now().saturating_sub(last);
+            if since < self.throttle {
+                log::info!("waiting {:.1?} between requests", self.throttle - since);
+                thread::sleep(self.throttle - since);
+            }
+        }
+        // a stamp we can't write only costs the throttle on the next run
+        let _ = write(&stamp, &now().as_millis().to_string());
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// A short name for the account behind a session token, the same on every run and build.
+fn account(session: &str) -> String {
+    // FNV-1a, as std's hashers are free to change between releases
+    let hash = session
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{hash:016x}")
+}
+
+/// The body of a response, or `what` went wrong and why.
+fn read(response: Result<ureq::Response, ureq::Error>, what: &str) -> Result<String, String> {
+    match response {
+        Ok(response) => response.into_string().map_err(|e| format!("{what}: {e}")),
+        Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(format!(
+            "{what}: the session token was rejected, so log in again"
+        )),
+        Err(ureq::Error::Status(404, _)) => Err(format!("{what}: it isn't out yet")),
+        Err(ureq::Error::Status(code @ 500..=599, _)) => Err(format!(
+            "{what}: the site had a problem ({code}), so try again in a while"
+        )),
+        Err(ureq::Error::Status(code, _)) => Err(format!("{what}: server said {code}")),
+        Err(ureq::Error::Transport(e)) => Err(format!("{what}: {e}")),
+    }
+}
+
+/// Writes through a temporary file, so an interrupted download never looks cached.
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    let fail = |e: std::io::Error| format!("could not write {}: {e}", path.display());
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(fail)?;
+    }
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, contents).map_err(fail)?;
+    std::fs::rename(&partial, path).map_err(fail)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        time::Instant,
+    };
+
+    /// A stand-in for the site on a local port, answering every request with `respond`
+    /// and keeping each request's first line and cookie.
+    pub(crate) struct Server {
+        pub base: String,
+        pub requests: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl Server {
+        pub(crate) fn start(respond: fn(&str, &str) -> (u16, String)) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let seen = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let (mut cookie, mut length) = (String::new(), 0);
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        let header = header.trim_end();
+                        if header.is_empty() {
+                            break;
+                        }
+                        let (name, value) = header.split_once(": ").unwrap();
+                        match name.to_ascii_lowercase().as_str() {
+                            "cookie" => cookie = value.to_string(),
+                            "content-length" => length = value.parse().unwrap(),
+                            _ => {}
+                        }
+                    }
+                    let mut body = vec![0; length];
+                    std::io::Read::read_exact(&mut reader, &mut body).unwrap();
+                    let body = String::from_utf8(body).unwrap();
+
+                    let (status, reply) = respond(line.trim_end(), &body);
+                    seen.lock()
+                        .unwrap()
+                        .push((line.trim_end().to_string(), cookie));
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{reply}",
+                        reply.len()
+                    )
+                    .unwrap();
+                }
+            });
+
+            Self { base, requests }
+        }
+    }
+
+    /// An empty directory of its own for each test.
+    pub(crate) fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn input(line: &str, _body: &str) -> (u16, String) {
+        match line {
+            "GET /2023/day/3/input HTTP/1.1" => (200, "467..114..\n".to_string()),
+            _ => (404, "Not Found".to_string()),
+        }
+    }
+
+    #[test]
+    fn fetches_once_then_reads_the_cache() {
+        let server = Server::start(input);
+        let client =
+            Client::new(&server.base, "abc", scratch("fetch-once")).throttled(Duration::ZERO);
+
+        let path = client.fetch(3).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "467..114..\n");
+        assert_eq!(client.fetch(3).unwrap(), path);
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(
+            *requests,
+            vec![(
+                "GET /2023/day/3/input HTTP/1.1".to_string(),
+                "session=abc".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn keeps_accounts_apart() {
+        let cache = scratch("accounts");
+        let alex = Client::new("", "alex", &cache);
+        let sam = Client::new("", "sam", &cache);
+        assert_ne!(alex.cached(1), sam.cached(1));
+        assert_eq!(account("alex"), "849a6d83aada5c25");
+    }
+
+    #[test]
+    fn explains_failures_without_caching() {
+        let server = Server::start(|_, _| (400, "Puzzle inputs differ by user.".to_string()));
+        let client =
+            Client::new(&server.base, "stale", scratch("rejected")).throttled(Duration::ZERO);
+
+        let error = client.fetch(3).unwrap_err();
+        assert!(error.contains("session token was rejected"), "{error}");
+        assert!(!client.cached(3).exists());
+
+        let server = Server::start(input);
+        let client =
+            Client::new(&server.base, "early", scratch("not-out")).throttled(Duration::ZERO);
+        assert!(client.fetch(25).unwrap_err().contains("isn't out yet"));
+    }
+
+    #[test]
+    fn tells_server_trouble_from_a_bad_token() {
+        let server = Server::start(|_, _| (500, "Internal Server Error".to_string()));
+        let client =
+            Client::new(&server.base, "fine", scratch("server-error")).throttled(Duration::ZERO);
+
+        let error = client.fetch(3).unwrap_err();
+        assert!(error.contains("had a problem (500)"), "{error}");
+        assert!(error.contains("try again"), "{error}");
+        assert!(!error.contains("session token"), "{error}");
+        assert!(!client.cached(3).exists());
+
+        let server = Server::start(|_, _| (403, "Forbidden".to_string()));
+        let client =
+            Client::new(&server.base, "gone", scratch("forbidden")).throttled(Duration::ZERO);
+        let error = client.fetch(3).unwrap_err();
+        assert!(error.contains("session token was rejected"), "{error}");
+    }
+
+    #[test]
+    fn spaces_out_requests() {
+        let server = Server::start(|_, _| (200, "input\n".to_string()));
+        let client = Client::new(&server.base, "abc", scratch("throttle"))
+            .throttled(Duration::from_millis(300));
+
+        let start = Instant::now();
+        client.fetch(1).unwrap();
+        client.fetch(2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+}
@@ -0,0 +1,153 @@
+use std::path::{Path, PathBuf};
+
+use crate::{answers::Expected, fetch};
+
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner lives inside the workspace")
+}
+
+/// Directory holding a day's crate and its real puzzle input.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_dir().join(format!("day-{day:02}"))
+}
+
+/// Directory holding a day's example inputs, `examples/NN/<name>.txt`.
+pub fn examples_dir(day: u8) -> PathBuf {
+    workspace_dir().join("examples").join(format!("{day:02}"))
+}
+
+/// Names of a day's example inputs, sorted.
+pub fn examples(day: u8) -> Result<Vec<String>, String> {
+    let dir = examples_dir(day);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let entries =
+        std::fs::read_dir(&dir).map_err(|e| format!("could not read {}: {e}", dir.display()))?;
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("could not read {}: {e}", dir.display()))?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// The example `--input test` stands for: the first `example` or `example-N` with an
+/// answer for `part`.
+fn default_example(day: u8, part: u8) -> Result<Option<String>, String> {
+    for name in examples(day)? {
+        let numbered = name
+            .strip_prefix("example-")
+            .is_some_and(|n| n.parse::<u32>().is_ok());
+        if name != "example" && !numbered {
+            continue;
+        }
+        if Expected::load(&Expected::path(day, &name))?
+            .part(part)
+            .is_some()
+        {
+            return Ok(Some(name));
+        }
+    }
+    Ok(None)
+}
+
+/// Resolves `--input` to a file on disk.
+///
+/// An existing path is used as is. Anything else is treated as the name of an
+/// example, so `example-2` finds `examples/NN/example-2.txt`, and `test` finds the
+/// day's default example for the part. No name at all finds the real puzzle input in
+/// the day's directory or in the `aoc fetch` cache.
+pub fn resolve(day: u8, part: u8, spec: Option<&str>) -> Result<PathBuf, String> {
+    if let Some(path) = spec.map(Path::new).filter(|p| p.is_file()) {
+        return Ok(path.to_path_buf());
+    }
+
+    let default = match spec {
+        Some("test") if !examples_dir(day).join("test.txt").is_file() => {
+            default_example(day, part)?
+        }
+        _ => None,
+    };
+    if let Some(name) = default.as_deref().or(spec) {
+        let path = examples_dir(day).join(format!("{name}.txt"));
+        if path.is_file() {
+            return Ok(path);
+        }
+        let known = examples(day)?;
+        return Err(if known.is_empty() {
+            format!("no example {name:?} for day {day} (there are none yet)")
+        } else {
+            format!(
+                "no example {name:?} for day {day} (try {})",
+                known.join(", ")
+            )
+        });
+    }
+
+    let dir = day_dir(day);
+    let mut candidates = vec![
+        format!("input-{part}.txt"),
+        "input-1.txt".to_string(),
+        "input.txt".to_string(),
+    ];
+    candidates.dedup();
+
+    // committed inputs come first, then whatever `aoc fetch` cached for this session
+    let fetched = fetch::Client::from_env()
+        .ok()
+        .map(|client| client.cached(day));
+    candidates
+        .iter()
+        .map(|name| dir.join(name))
+        .chain(fetched)
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            format!(
+                "no input for day {day} part {part} (looked for {} in {}, or try aoc fetch)",
+                candidates.join(", "),
+                dir.display()
+            )
+        })
+}
+
+pub fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))
+}
+
+pub fn read_stdin() -> Result<String, String> {
+    std::io::read_to_string(std::io::stdin()).map_err(|e| format!("could not read stdin: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(2, 1, "example")]
+    #[case(1, 1, "example-1")]
+    #[case(1, 2, "example-2")]
+    #[case(8, 2, "example-3")]
+    #[case(17, 1, "example")]
+    fn test_finds_default_example(#[case] day: u8, #[case] part: u8, #[case] name: &str) {
+        let path = resolve(day, part, Some("test")).unwrap();
+        assert_eq!(path, examples_dir(day).join(format!("{name}.txt")));
+    }
+
+    #[test]
+    fn names_examples_when_none_matches() {
+        let err = resolve(2, 1, Some("missing")).unwrap_err();
+        assert_eq!(err, "no example \"missing\" for day 2 (try example)");
+    }
+}
@@ -0,0 +1,45 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Writes solver log records to stderr, leaving stdout to the answers.
+struct Stderr;
+
+impl Log for Stderr {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Level for a number of `-v` flags: warnings only, then debug, then trace.
+pub fn level(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+pub fn init(verbosity: u8) {
+    log::set_logger(&Stderr).expect("logger is only set once");
+    log::set_max_level(level(verbosity));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_verbosity_flags() {
+        assert_eq!(level(0), LevelFilter::Warn);
+        assert_eq!(level(1), LevelFilter::Debug);
+        assert_eq!(level(2), LevelFilter::Trace);
+        assert_eq!(level(5), LevelFilter::Trace);
+    }
+}
@@ -0,0 +1,692 @@
+use std::{
+    io::IsTerminal,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_core::Image;
+use clap::{builder::RangedU64ValueParser, Args, Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+
+use answers::{Manifest, Outcome, REAL};
+use bench::{Report, Row};
+use record::{Record, Status};
+use submit::Verdict;
+
+mod alloc;
+mod answers;
+mod bench;
+mod days;
+mod diagnostic;
+mod fetch;
+mod input;
+mod logger;
+mod record;
+mod scaffold;
+mod stepper;
+mod submit;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Log what the solvers are doing to stderr; repeat for more detail
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solution against one of its inputs
+    Run(RunArgs),
+    /// Time each day's parse and solve steps over several runs
+    Bench(BenchArgs),
+    /// Check answers against each day's answers.toml and example sidecars
+    Verify(VerifyArgs),
+    /// Draw a day's solution as an image, or as a numbered sequence of frames
+    Render(RenderArgs),
+    /// Step through a day's simulation in the terminal
+    Step(StepArgs),
+    /// Make up a random puzzle input for a day
+    Gen(GenArgs),
+    /// Download a day's puzzle input into the local cache
+    Fetch(FetchArgs),
+    /// Send a day's answer for the real input to the site and record the verdict
+    Submit(SubmitArgs),
+    /// Set up the crate, example and runner entries for the next day
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day of the puzzle, 1-25
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Run every solved day at once on a thread pool
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// Part to run; both parts are run when omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file path, an example name such as `example` or `alex-1`, `test` for the part's
+    /// default example, or `-` for stdin
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Print answers as text, or as one JSON record per part
+    #[arg(long, value_enum, default_value_t = Output::Text)]
+    format: Output,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark; every solved day is benchmarked when omitted
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Part to benchmark; both parts are benchmarked when omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file path or example name, as for `run`
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Timed runs per part
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Format of the results table
+    #[arg(long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+
+    /// Write the results to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Earlier `--format json` results to compare against
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Slowdown against the baseline, in percent, that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    tolerance: f64,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Day to check; every solved day is checked when omitted
+    #[arg(long)]
+    day: Option<u8>,
+
+    /// Print results as text, or as one JSON record per check
+    #[arg(long, value_enum, default_value_t = Output::Text)]
+    format: Output,
+}
+
+#[derive(Args)]
+struct RenderArgs {
+    /// Day to draw
+    #[arg(long)]
+    day: u8,
+
+    /// Input file path or example name, as for `run`
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Write the final frame here, as PNG or SVG depending on the extension
+    #[arg(long, required_unless_present = "frames")]
+    output: Option<PathBuf>,
+
+    /// Write every frame into this directory as `frame-0000.png` and so on
+    #[arg(long)]
+    frames: Option<PathBuf>,
+
+    /// Image format for `--frames`
+    #[arg(long, value_enum, default_value_t = ImageFormat::Png)]
+    format: ImageFormat,
+
+    /// Pixels per grid cell
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..=64))]
+    scale: u32,
+}
+
+#[derive(Args)]
+struct StepArgs {
+    /// Day to step through
+    #[arg(long)]
+    day: u8,
+
+    /// Input file path or example name, as for `run`
+    #[arg(long)]
+    input: Option<String>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to make an input for
+    #[arg(long)]
+    day: u8,
+
+    /// How big an input to make, roughly lines or the side of a grid; see each day's generator
+    #[arg(long, default_value_t = 10, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    size: usize,
+
+    /// Seed for the same input every time; when omitted a random one is used, shown with -v
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Write the input to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day of the puzzle, 1-25
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day of the puzzle, 1-25
+    #[arg(long)]
+    day: u8,
+
+    /// Part to submit
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day of the puzzle, which must be the one after the last solved day
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Markdown,
+    Json,
+}
+
+/// How `run` and `verify` print their results; JSON records are described in `record.rs`.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Output {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ImageFormat {
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    fn from_path(path: &Path) -> Result<Self, String> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => Ok(ImageFormat::Png),
+            Some("svg") => Ok(ImageFormat::Svg),
+            _ => Err(format!(
+                "don't know how to write {}, use a .png or .svg file",
+                path.display()
+            )),
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Svg => "svg",
+        }
+    }
+
+    fn encode(self, image: &Image, scale: u32) -> Vec<u8> {
+        match self {
+            ImageFormat::Png => image.to_png(scale),
+            ImageFormat::Svg => image.to_svg(scale).into_bytes(),
+        }
+    }
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    let Some(day) = args.day else {
+        return run_all(args);
+    };
+    if !days::SOLVED.contains(&day) {
+        return Err(format!("no solution for day {day}"));
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let stdin = match args.input.as_deref() {
+        Some("-") => Some(input::read_stdin()?),
+        _ => None,
+    };
+    let name = match args.input.as_deref() {
+        None => REAL,
+        Some("-") => "stdin",
+        Some(spec) => spec,
+    };
+
+    let mut unanswered = 0;
+    for part in parts {
+        let loaded = match &stdin {
+            Some(input) => Ok((input.clone(), "<stdin>".to_string())),
+            None => input::resolve(day, part, args.input.as_deref())
+                .and_then(|path| Ok((input::read(&path)?, path.display().to_string()))),
+        };
+
+        if args.format == Output::Json {
+            let record = solve(day, part, name, loaded.map(|(input, _)| input));
+            if record.status != Status::Ok {
+                unanswered += 1;
+            }
+            println!("{}", record.to_json());
+            continue;
+        }
+
+        let (input, origin) = loaded?;
+        let answer = days::run(day, part, &input)
+            .ok_or_else(|| format!("no solution for day {day} part {part}"))?
+            .map_err(|e| diagnostic::explain(&e, &input, &origin))?;
+        println!("Day {day} part {part}: {answer}");
+    }
+
+    if unanswered > 0 {
+        return Err(format!("{unanswered} parts went unanswered"));
+    }
+    Ok(())
+}
+
+/// Solves every day and part on rayon's thread pool, printing them in order once all are done.
+fn run_all(args: &RunArgs) -> Result<(), String> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let name = match args.input.as_deref() {
+        None => REAL,
+        Some("-") => return Err("--all can't share one input from stdin".to_string()),
+        Some(spec) => spec,
+    };
+
+    let runs = days::SOLVED
+        .flat_map(|day| parts.iter().map(move |&part| (day, part)))
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+    let records = runs
+        .into_par_iter()
+        .map(|(day, part)| {
+            let input = input::resolve(day, part, args.input.as_deref())
+                .and_then(|path| input::read(&path));
+            solve(day, part, name, input)
+        })
+        .collect::<Vec<_>>();
+    let wall = start.elapsed();
+
+    let mut unanswered = 0;
+    for record in &records {
+        if record.status != Status::Ok {
+            unanswered += 1;
+        }
+        match (args.format, &record.answer) {
+            (Output::Json, _) => println!("{}", record.to_json()),
+            (Output::Text, Some(answer)) => {
+                println!("Day {} part {}: {answer}", record.day, record.part)
+            }
+            (Output::Text, None) => {
+                println!(
+                    "Day {} part {}: {}",
+                    record.day,
+                    record.part,
+                    record.verdict()
+                )
+            }
+        }
+    }
+
+    let busy = records
+        .iter()
+        .map(|record| record.parse_ns.unwrap_or(0) + record.solve_ns.unwrap_or(0))
+        .sum::<u64>();
+    // the summary goes to stderr so JSON output stays one record per line
+    eprintln!(
+        "{} parts in {wall:.2?} on {} threads ({:.2?} spent solving)",
+        records.len(),
+        rayon::current_num_threads(),
+        Duration::from_nanos(busy),
+    );
+
+    if unanswered > 0 {
+        return Err(format!("{unanswered} parts went unanswered"));
+    }
+    Ok(())
+}
+
+/// Runs one part, turning everything that can go wrong into the record's status.
+fn solve(day: u8, part: u8, name: &str, input: Result<String, String>) -> Record {
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => return Record::new(day, part, name, Status::Missing).because(e),
+    };
+    match days::solve(day, part, &input) {
+        Some(Ok(solved)) => {
+            let mut record = Record::new(day, part, name, Status::Ok)
+                .timed(solved.timing.parse, solved.timing.solve);
+            record.answer = Some(solved.answer);
+            record
+        }
+        Some(Err(e)) => Record::new(day, part, name, Status::Error).because(e.to_string()),
+        None => Record::new(day, part, name, Status::Missing)
+            .because(format!("no solution for part {part}")),
+    }
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let days = match args.day {
+        Some(day) if days::SOLVED.contains(&day) => vec![day],
+        Some(day) => return Err(format!("no solution for day {day}")),
+        None => days::SOLVED.collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    if log::log_enabled!(log::Level::Debug) {
+        eprintln!("warning: logging is on, so the timings include writing it out");
+    }
+
+    let baseline = match &args.baseline {
+        Some(path) => Some(Report::from_json(&input::read(path)?)?),
+        None => None,
+    };
+
+    let mut report = Report {
+        runs: args.runs as usize,
+        results: Vec::new(),
+    };
+    for day in days {
+        for &part in &parts {
+            let path = match input::resolve(day, part, args.input.as_deref()) {
+                Ok(path) => path,
+                // a missing input shouldn't stop a run over every day
+                Err(e) if args.day.is_none() => {
+                    eprintln!("skipping: {e}");
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            let input = input::read(&path)?;
+
+            let timings = (0..args.runs)
+                .map(|_| {
+                    days::time(day, part, &input)
+                        .ok_or_else(|| format!("no solution for day {day} part {part}"))?
+                        .map_err(|e| diagnostic::explain(&e, &input, &path.display().to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            report.results.push(Row::new(day, part, &timings));
+        }
+    }
+
+    if let Some(baseline) = &baseline {
+        report.compare(baseline);
+    }
+
+    let table = match args.format {
+        Format::Markdown => report.to_markdown(),
+        Format::Json => report.to_json() + "\n",
+    };
+    match &args.output {
+        Some(path) => write(path, table.as_bytes())?,
+        None => print!("{table}"),
+    }
+
+    let slower = report
+        .regressions(args.tolerance / 100.0)
+        .map(|row| format!("day {} part {}", row.day, row.part))
+        .collect::<Vec<_>>();
+    if !slower.is_empty() {
+        return Err(format!(
+            "more than {}% slower than the baseline: {}",
+            args.tolerance,
+            slower.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Runs one part against a named input and compares it with the manifest.
+fn check(day: u8, part: u8, name: &str, manifest: &Manifest) -> Record {
+    let Some(expected) = manifest.expected(name, part) else {
+        return Record::new(day, part, name, Status::Missing).because("no expected answer");
+    };
+    let spec = (name != REAL).then_some(name);
+    let input = input::resolve(day, part, spec).and_then(|path| input::read(&path));
+
+    let mut record = solve(day, part, name, input);
+    record.expected = Some(expected.to_string());
+    if let Some(answer) = &record.answer {
+        record = match Outcome::check(expected, answer) {
+            Outcome::Pass => Record {
+                status: Status::Pass,
+                ..record
+            },
+            Outcome::Fail(why) => Record {
+                status: Status::Fail,
+                ..record
+            }
+            .because(why),
+        };
+    }
+    record
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let days = match args.day {
+        Some(day) if days::SOLVED.contains(&day) => vec![day],
+        Some(day) => return Err(format!("no solution for day {day}")),
+        None => days::SOLVED.collect(),
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days {
+        let manifest = Manifest::load(day)?;
+        // the real input is always checked, so a day without answers shows up as missing
+        let names = std::iter::once(REAL)
+            .chain(manifest.inputs().filter(|name| *name != REAL))
+            .collect::<Vec<_>>();
+        for part in [1, 2] {
+            for &name in &names {
+                if name != REAL && manifest.expected(name, part).is_none() {
+                    continue;
+                }
+                let record = check(day, part, name, &manifest);
+                match record.status {
+                    Status::Pass => passed += 1,
+                    Status::Fail | Status::Error => failed += 1,
+                    Status::Ok | Status::Missing => missing += 1,
+                }
+                match args.format {
+                    Output::Text => {
+                        println!("day {day:02} part {part} ({name}): {}", record.verdict())
+                    }
+                    Output::Json => println!("{}", record.to_json()),
+                }
+            }
+        }
+    }
+
+    if args.format == Output::Text {
+        println!("{passed} passed, {failed} failed, {missing} missing");
+    }
+    if failed > 0 {
+        return Err(format!("{failed} answers did not match"));
+    }
+    Ok(())
+}
+
+fn write(path: &Path, contents: &[u8]) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+fn render(args: &RenderArgs) -> Result<(), String> {
+    if !days::ANIMATED.contains(&args.day) {
+        let animated = days::ANIMATED.map(|day| day.to_string());
+        return Err(format!(
+            "day {} has no animation (try {})",
+            args.day,
+            animated.join(", ")
+        ));
+    }
+
+    let path = input::resolve(args.day, 1, args.input.as_deref())?;
+    let input = input::read(&path)?;
+    let frames = days::frames(args.day, &input)
+        .expect("day is animated")
+        .map_err(|e| diagnostic::render(&e, &input, &path.display().to_string()))?;
+
+    if let Some(dir) = &args.frames {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+        for (i, frame) in frames.iter().enumerate() {
+            let name = format!("frame-{i:04}.{}", args.format.extension());
+            write(&dir.join(name), &args.format.encode(frame, args.scale))?;
+        }
+        println!("wrote {} frames to {}", frames.len(), dir.display());
+    }
+
+    if let Some(output) = &args.output {
+        let format = ImageFormat::from_path(output)?;
+        let last = frames
+            .last()
+            .expect("every animation starts with its input");
+        write(output, &format.encode(last, args.scale))?;
+        println!("wrote {}", output.display());
+    }
+    Ok(())
+}
+
+fn step(args: &StepArgs) -> Result<(), String> {
+    if !days::SIMULATED.contains(&args.day) {
+        let simulated = days::SIMULATED.map(|day| day.to_string());
+        return Err(format!(
+            "day {} has no simulation (try {})",
+            args.day,
+            simulated.join(", ")
+        ));
+    }
+
+    let path = input::resolve(args.day, 1, args.input.as_deref())?;
+    let input = input::read(&path)?;
+    let session = stepper::Session {
+        input: std::io::stdin().lock(),
+        output: std::io::stdout().lock(),
+        clear: std::io::stdout().is_terminal(),
+    };
+    days::simulate(args.day, &input, session)
+        .expect("day is simulated")
+        .map_err(|e| diagnostic::render(&e, &input, &path.display().to_string()))?
+        .map_err(|e| format!("terminal went away: {e}"))
+}
+
+fn generate(args: &GenArgs) -> Result<(), String> {
+    let seed = args.seed.unwrap_or_else(rand::random);
+    log::info!("day {} size {} seed {seed}", args.day, args.size);
+    let input = days::generate(args.day, seed, args.size)
+        .ok_or_else(|| format!("no solution for day {}", args.day))?;
+
+    match &args.output {
+        Some(path) => write(path, input.as_bytes()),
+        None => {
+            print!("{input}");
+            Ok(())
+        }
+    }
+}
+
+fn fetch(args: &FetchArgs) -> Result<(), String> {
+    let path = fetch::Client::from_env()?.fetch(args.day)?;
+    println!("{}", path.display());
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<(), String> {
+    let (day, part) = (args.day, args.part);
+    let path = input::resolve(day, part, None)?;
+    let input = input::read(&path)?;
+    let answer = days::run(day, part, &input)
+        .ok_or_else(|| format!("no solution for day {day}"))?
+        .map_err(|e| diagnostic::explain(&e, &input, &path.display().to_string()))?;
+
+    Manifest::load(day)?
+        .screen(part, &answer)
+        .map_err(|why| format!("not submitting: {why}"))?;
+
+    match fetch::Client::from_env()?.submit(day, part, &answer)? {
+        Verdict::Right => {
+            answers::record(day, part, &answer, Ok(()))?;
+            println!("{answer} is right");
+            Ok(())
+        }
+        Verdict::Rejected(reason) => {
+            answers::record(day, part, &answer, Err(reason))?;
+            Err(format!("{answer} is {reason}"))
+        }
+        Verdict::Wait(left) => Err(format!(
+            "answered too recently, try again in {}s",
+            left.as_secs()
+        )),
+        Verdict::WrongLevel => Err(format!(
+            "day {day} part {part} isn't taking answers; it may be solved already"
+        )),
+    }
+}
+
+fn new(args: &NewArgs) -> Result<(), String> {
+    let root = input::workspace_dir();
+    for path in scaffold::scaffold(root, args.day)? {
+        println!("{}", path.strip_prefix(root).unwrap_or(&path).display());
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logger::init(cli.verbose);
+
+    let result = match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Render(args) => render(args),
+        Command::Step(args) => step(args),
+        Command::Gen(args) => generate(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
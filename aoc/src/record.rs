@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Version of the [`Record`] layout, bumped whenever a field changes meaning or goes away.
+pub const SCHEMA: u32 = 1;
+
+/// How one run of one part turned out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// Solved, with nothing to check the answer against.
+    Ok,
+    /// Solved, and the answer matched the expected one.
+    Pass,
+    /// Solved, but the answer was not the expected one.
+    Fail,
+    /// There was no input, expected answer or solution to run.
+    Missing,
+    /// The input could not be parsed or the puzzle could not be solved.
+    Error,
+}
+
+/// One line of `--format json` output from `run` and `verify`.
+///
+/// Every field is always written, as `null` when it doesn't apply, so scripts can rely on
+/// the keys:
+///
+/// ```json
+/// {"schema":1,"day":1,"part":1,"input":"real","status":"ok","answer":"54304",
+///  "expected":null,"parse_ns":41250,"solve_ns":83125,"error":null}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub schema: u32,
+    pub day: u8,
+    pub part: u8,
+    /// `real`, an example name, `stdin` or the path given to `--input`.
+    pub input: String,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    /// Why the status is `fail`, `missing` or `error`.
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(day: u8, part: u8, input: &str, status: Status) -> Self {
+        Self {
+            schema: SCHEMA,
+            day,
+            part,
+            input: input.to_string(),
+            status,
+            answer: None,
+            expected: None,
+            parse_ns: None,
+            solve_ns: None,
+            error: None,
+        }
+    }
+
+    pub fn timed(mut self, parse: Duration, solve: Duration) -> Self {
+        let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+        self.parse_ns = Some(nanos(parse));
+        self.solve_ns = Some(nanos(solve));
+        self
+    }
+
+    pub fn because(mut self, why: impl Into<String>) -> Self {
+        self.error = Some(why.into());
+        self
+    }
+
+    /// The status as `verify` prints it, with the reason when something is wrong.
+    pub fn verdict(&self) -> String {
+        let why = self.error.as_deref().unwrap_or("no reason given");
+        match self.status {
+            Status::Ok => "ok".to_string(),
+            Status::Pass => "pass".to_string(),
+            Status::Fail | Status::Error => format!("FAIL, {why}"),
+            Status::Missing => format!("missing, {why}"),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("record only holds plain data")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_every_field() {
+        let mut record = Record::new(1, 2, "example-1", Status::Pass)
+            .timed(Duration::from_nanos(1500), Duration::from_micros(2));
+        record.answer = Some("281".to_string());
+        record.expected = Some("281".to_string());
+        assert_eq!(
+            record.to_json(),
+            r#"{"schema":1,"day":1,"part":2,"input":"example-1","status":"pass","answer":"281","expected":"281","parse_ns":1500,"solve_ns":2000,"error":null}"#
+        );
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let record = Record::new(20, 2, "real", Status::Error).because("expected a pipe");
+        assert_eq!(
+            serde_json::from_str::<Record>(&record.to_json()).unwrap(),
+            record
+        );
+    }
+}
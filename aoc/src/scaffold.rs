@@ -0,0 +1,315 @@
+use std::path::{Path, PathBuf};
+
+use crate::input;
+
+/// Files of a new day's crate, relative to its directory, with `{{day}}` and `{{dd}}` to fill in.
+const TEMPLATE: [(&str, &str); 6] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/day/Cargo.toml.tmpl"),
+    ),
+    (
+        "answers.toml",
+        include_str!("../templates/day/answers.toml.tmpl"),
+    ),
+    (
+        "src/lib.rs",
+        include_str!("../templates/day/src/lib.rs.tmpl"),
+    ),
+    (
+        "src/part1.rs",
+        include_str!("../templates/day/src/part1.rs.tmpl"),
+    ),
+    (
+        "src/part2.rs",
+        include_str!("../templates/day/src/part2.rs.tmpl"),
+    ),
+    (
+        "src/generate.rs",
+        include_str!("../templates/day/src/generate.rs.tmpl"),
+    ),
+];
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("{{day}}", &day.to_string())
+        .replace("{{dd}}", &format!("{day:02}"))
+}
+
+/// Sets up `day` under the workspace at `root`: its crate, an empty example with its answers
+/// sidecar, and the runner entries that make `aoc run --day` find it.
+///
+/// Returns every file written or changed.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let days_rs = root.join("aoc/src/days.rs");
+    let runner_toml = root.join("aoc/Cargo.toml");
+    let days = input::read(&days_rs)?;
+    let last = last_solved(&days).ok_or("could not find SOLVED in aoc/src/days.rs")?;
+    if day != last + 1 {
+        return Err(format!(
+            "days are added in order, so the next one is day {}",
+            last + 1
+        ));
+    }
+
+    let dir = root.join(format!("day-{day:02}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let examples = root.join("examples").join(format!("{day:02}"));
+    if examples.exists() {
+        return Err(format!("{} already exists", examples.display()));
+    }
+
+    let manifest = input::read(&runner_toml)?;
+    let mut files = TEMPLATE
+        .iter()
+        .map(|(name, template)| (dir.join(name), fill(template, day)))
+        .collect::<Vec<_>>();
+    files.push((examples.join("example.txt"), String::new()));
+    files.push((examples.join("example.toml"), String::new()));
+    files.push((days_rs.clone(), wire_days(&days, last, day)));
+    files.push((runner_toml.clone(), wire_runner(&manifest, last, day)?));
+
+    // everything is written off to one side first, so a failed write leaves the workspace
+    // alone, then moved into place a directory or file at a time
+    let staging = root.join(format!(".day-{day:02}.new"));
+    let result = write_all(root, &staging, &files).and_then(|()| {
+        install(
+            root,
+            &staging,
+            &[dir, examples],
+            &[(days_rs, days), (runner_toml, manifest)],
+        )
+    });
+    let _ = std::fs::remove_dir_all(&staging);
+    result?;
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Where `path`, somewhere under `root`, is written while it waits in `staging`.
+fn staged(root: &Path, staging: &Path, path: &Path) -> PathBuf {
+    staging.join(
+        path.strip_prefix(root)
+            .expect("scaffolded files are under the root"),
+    )
+}
+
+fn write_all(root: &Path, staging: &Path, files: &[(PathBuf, String)]) -> Result<(), String> {
+    for (path, contents) in files {
+        let path = staged(root, staging, path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("could not create {}: {e}", parent.display()))?;
+        }
+        std::fs::write(&path, contents)
+            .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Moves the staged `created` directories and then `replaced` files into place, putting
+/// back what was there before if any move fails. `replaced` holds each file's old contents.
+fn install(
+    root: &Path,
+    staging: &Path,
+    created: &[PathBuf],
+    replaced: &[(PathBuf, String)],
+) -> Result<(), String> {
+    let targets = created.iter().chain(replaced.iter().map(|(path, _)| path));
+    for (moved, path) in targets.enumerate() {
+        let parent = path.parent().expect("scaffolded files are under the root");
+        let made = std::fs::create_dir_all(parent);
+        if let Err(e) = made.and_then(|()| std::fs::rename(staged(root, staging, path), path)) {
+            for dir in created.iter().take(moved) {
+                let _ = std::fs::remove_dir_all(dir);
+            }
+            for (file, before) in replaced.iter().take(moved.saturating_sub(created.len())) {
+                let _ = std::fs::write(file, before);
+            }
+            return Err(format!("could not move {} into place: {e}", path.display()));
+        }
+    }
+    Ok(())
+}
+
+/// The last day in `SOLVED`, read from the source of `days.rs`.
+fn last_solved(days: &str) -> Option<u8> {
+    let line = days
+        .lines()
+        .find(|line| line.starts_with("pub const SOLVED"))?;
+    let (_, last) = line.split_once("..=")?;
+    last.trim_end_matches(';').parse().ok()
+}
+
+/// `days.rs` with `day` added to `SOLVED` and given the same dispatch arms as `last`.
+fn wire_days(days: &str, last: u8, day: u8) -> String {
+    let (from, to) = (format!("{last:02}"), format!("{day:02}"));
+    let mut out = String::new();
+    for line in days.lines() {
+        if line.starts_with("pub const SOLVED") {
+            out.push_str(&line.replace(&format!("..={last}"), &format!("..={day}")));
+        } else {
+            out.push_str(line);
+        }
+        out.push('\n');
+
+        // only the arms every day has; the animated and simulated days are picked by hand
+        let arm = line.trim_start().starts_with(&format!("{last} =>"))
+            && (line.contains("visitor.visit::<") || line.contains("generated::<"));
+        if arm {
+            let added = line
+                .replacen(&format!("{last} =>"), &format!("{day} =>"), 1)
+                .replace(
+                    &format!("day_{from}::Day{from}"),
+                    &format!("day_{to}::Day{to}"),
+                );
+            out.push_str(&added);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// The runner's `Cargo.toml` with the new day's crate as a dependency, after the last one.
+fn wire_runner(manifest: &str, last: u8, day: u8) -> Result<String, String> {
+    let after = format!("day-{last:02} = {{ path = \"../day-{last:02}\" }}\n");
+    let added = format!("day-{day:02} = {{ path = \"../day-{day:02}\" }}\n");
+    let at = manifest
+        .find(&after)
+        .ok_or_else(|| format!("could not find day-{last:02} in aoc/Cargo.toml"))?;
+    let at = at + after.len();
+    Ok(format!("{}{added}{}", &manifest[..at], &manifest[at..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::scratch;
+
+    const DAYS: &str = "\
+pub const SOLVED: RangeInclusive<u8> = 1..=9;
+
+fn with_day<V: Visit>(day: u8, visitor: V) -> Option<V::Output> {
+    Some(match day {
+        9 => visitor.visit::<day_09::Day09>(),
+        _ => return None,
+    })
+}
+
+pub fn frames(day: u8, input: &str) -> Option<Result<Vec<Image>, ParseError>> {
+    Some(match day {
+        9 => animate::<day_09::Day09>(input),
+        _ => return None,
+    })
+}
+
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    Some(match day {
+        9 => generated::<day_09::Day09>(seed, size),
+        _ => return None,
+    })
+}
+";
+
+    #[test]
+    fn fills_in_the_day() {
+        for (name, template) in TEMPLATE {
+            let filled = fill(template, 7);
+            assert!(!filled.contains("{{"), "{name} has a gap left");
+            assert!(
+                !filled.contains("todo!"),
+                "{name} panics until it's filled in"
+            );
+        }
+        assert!(fill(TEMPLATE[2].1, 7).contains("pub struct Day07;"));
+        assert!(fill(TEMPLATE[2].1, 7).contains("const DAY: u8 = 7;"));
+    }
+
+    #[test]
+    fn wires_the_runner() {
+        let days = wire_days(DAYS, 9, 10);
+        assert_eq!(last_solved(&days), Some(10));
+        assert!(days.contains(
+            "        9 => visitor.visit::<day_09::Day09>(),\n        10 => visitor.visit::<day_10::Day10>(),\n"
+        ));
+        assert!(days.contains("        10 => generated::<day_10::Day10>(seed, size),\n"));
+        assert!(!days.contains("animate::<day_10"));
+
+        let manifest = "[dependencies]\nday-09 = { path = \"../day-09\" }\n\n[dev-dependencies]\n";
+        assert_eq!(
+            wire_runner(manifest, 9, 10).unwrap(),
+            "[dependencies]\nday-09 = { path = \"../day-09\" }\nday-10 = { path = \"../day-10\" }\n\n[dev-dependencies]\n"
+        );
+    }
+
+    #[test]
+    fn scaffolds_only_the_next_day() {
+        let root = scratch("scaffold");
+        std::fs::create_dir_all(root.join("aoc/src")).unwrap();
+        std::fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+        std::fs::write(
+            root.join("aoc/Cargo.toml"),
+            "day-09 = { path = \"../day-09\" }\n",
+        )
+        .unwrap();
+
+        assert!(scaffold(&root, 11)
+            .unwrap_err()
+            .contains("next one is day 10"));
+        let written = scaffold(&root, 10).unwrap();
+        assert_eq!(written.len(), TEMPLATE.len() + 4);
+        assert!(root.join("day-10/src/part1.rs").is_file());
+        assert!(root.join("examples/10/example.toml").is_file());
+        assert!(scaffold(&root, 10)
+            .unwrap_err()
+            .contains("next one is day 11"));
+    }
+
+    #[test]
+    fn leaves_nothing_behind_when_a_write_fails() {
+        let root = scratch("scaffold-fails");
+        std::fs::create_dir_all(root.join("aoc/src")).unwrap();
+        std::fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+        let manifest = "day-09 = { path = \"../day-09\" }\n";
+        std::fs::write(root.join("aoc/Cargo.toml"), manifest).unwrap();
+        // a file where the staging directory should go stops the first write
+        std::fs::write(root.join(".day-10.new"), "").unwrap();
+
+        assert!(scaffold(&root, 10).unwrap_err().contains("could not"));
+        assert!(!root.join("day-10").exists());
+        assert!(!root.join("examples/10").exists());
+        let days = std::fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert_eq!(days, DAYS);
+        let runner = std::fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        assert_eq!(runner, manifest);
+    }
+
+    #[test]
+    fn puts_back_what_it_moved_when_a_move_fails() {
+        let root = scratch("scaffold-install");
+        let staging = root.join("staging");
+        std::fs::create_dir_all(staging.join("day-10")).unwrap();
+        std::fs::write(staging.join("days.rs"), "new").unwrap();
+        std::fs::write(root.join("days.rs"), "old").unwrap();
+
+        // nothing staged for the manifest, so the last move fails
+        let err = install(
+            &root,
+            &staging,
+            &[root.join("day-10")],
+            &[
+                (root.join("days.rs"), "old".to_string()),
+                (root.join("Cargo.toml"), String::new()),
+            ],
+        )
+        .unwrap_err();
+        assert!(err.contains("Cargo.toml"));
+        assert!(!root.join("day-10").exists());
+        assert_eq!(
+            std::fs::read_to_string(root.join("days.rs")).unwrap(),
+            "old"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, Write},
+};
+
+use aoc_core::{Event, Step};
+
+use crate::days::Drive;
+
+/// Steps `c` takes looking for a breakpoint before giving up.
+const MAX_RUN: usize = 10_000;
+
+/// States kept for going back.
+const HISTORY: usize = 1_000;
+
+const HELP: &str = "\
+commands:
+  enter, s   step once
+  n N, N     step N times
+  c          run to the next breakpoint
+  b, b N     go back one or N steps
+  q          quit";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Step(usize),
+    Continue,
+    Back(usize),
+    Help,
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+        let count = |word: Option<&str>| {
+            word.map_or(Ok(1), |n| {
+                n.parse()
+                    .map_err(|_| format!("{n:?} is not a number of steps"))
+            })
+        };
+        match words.next() {
+            None | Some("s") => Ok(Command::Step(1)),
+            Some("n") => count(words.next()).map(Command::Step),
+            Some("c") => Ok(Command::Continue),
+            Some("b") => count(words.next()).map(Command::Back),
+            Some("h" | "?") => Ok(Command::Help),
+            Some("q") => Ok(Command::Quit),
+            Some(word) => word
+                .parse()
+                .map(Command::Step)
+                .map_err(|_| format!("unknown command {word:?}, ? for help")),
+        }
+    }
+}
+
+/// The states stepped through so far, the current one last.
+struct Timeline<S> {
+    states: VecDeque<S>,
+    /// Steps taken to reach the current state.
+    steps: usize,
+}
+
+impl<S: Step> Timeline<S> {
+    fn current(&self) -> &S {
+        self.states.back().expect("timeline is never empty")
+    }
+
+    /// Takes up to `limit` steps, returning a note on how the last one went.
+    fn advance(&mut self, limit: usize, to_breakpoint: bool) -> String {
+        let mut note = String::new();
+        for _ in 0..limit {
+            let mut next = self.current().clone();
+            let event = next.step();
+            if event == Event::Finished {
+                return "finished".to_string();
+            }
+
+            self.states.push_back(next);
+            if self.states.len() > HISTORY {
+                self.states.pop_front();
+            }
+            self.steps += 1;
+
+            note = match event {
+                Event::Breakpoint(why) if to_breakpoint => return format!("breakpoint: {why}"),
+                Event::Breakpoint(why) => format!("breakpoint: {why}"),
+                _ => String::new(),
+            };
+        }
+        if to_breakpoint {
+            format!("no breakpoint in {limit} steps")
+        } else {
+            note
+        }
+    }
+
+    fn back(&mut self, count: usize) -> String {
+        let count = count.min(self.states.len() - 1);
+        self.states.truncate(self.states.len() - count);
+        self.steps -= count;
+        match count {
+            0 => "can't go back any further".to_string(),
+            _ => format!("back {count}"),
+        }
+    }
+}
+
+/// Drives a simulation from commands read one per line, showing the state after each.
+pub struct Session<R, W> {
+    pub input: R,
+    pub output: W,
+    /// Clears the terminal before showing each state.
+    pub clear: bool,
+}
+
+impl<R: BufRead, W: Write> Drive for Session<R, W> {
+    type Output = io::Result<()>;
+
+    fn drive<S: Step>(mut self, start: S) -> io::Result<()> {
+        let mut timeline = Timeline {
+            states: VecDeque::from([start]),
+            steps: 0,
+        };
+        let mut note = "? for help".to_string();
+        loop {
+            if self.clear {
+                write!(self.output, "\x1b[2J\x1b[H")?;
+            }
+            write!(self.output, "{}", timeline.current().view())?;
+            writeln!(self.output, "step {}: {note}", timeline.steps)?;
+            write!(self.output, "> ")?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            note = match Command::parse(&line) {
+                Ok(Command::Step(count)) => timeline.advance(count, false),
+                Ok(Command::Continue) => timeline.advance(MAX_RUN, true),
+                Ok(Command::Back(count)) => timeline.back(count),
+                Ok(Command::Help) => HELP.to_string(),
+                Ok(Command::Quit) => return Ok(()),
+                Err(e) => e,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts to 12, breaking on multiples of 5.
+    #[derive(Clone)]
+    struct Counter(u32);
+
+    impl Step for Counter {
+        fn step(&mut self) -> Event {
+            if self.0 == 12 {
+                return Event::Finished;
+            }
+            self.0 += 1;
+            match self.0 % 5 {
+                0 => Event::Breakpoint(format!("{} is a multiple of 5", self.0)),
+                _ => Event::Quiet,
+            }
+        }
+
+        fn view(&self) -> String {
+            format!("[{}]\n", self.0)
+        }
+    }
+
+    fn transcript(commands: &str) -> Vec<String> {
+        let mut output = Vec::new();
+        Session {
+            input: commands.as_bytes(),
+            output: &mut output,
+            clear: false,
+        }
+        .drive(Counter(0))
+        .unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .filter(|line| line.starts_with("> step") || line.starts_with("step"))
+            .map(|line| line.trim_start_matches("> ").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(Command::parse("\n"), Ok(Command::Step(1)));
+        assert_eq!(Command::parse("n 20\n"), Ok(Command::Step(20)));
+        assert_eq!(Command::parse("7"), Ok(Command::Step(7)));
+        assert_eq!(Command::parse("b"), Ok(Command::Back(1)));
+        assert!(Command::parse("n many").is_err());
+        assert!(Command::parse("jump").is_err());
+    }
+
+    #[test]
+    fn steps_runs_and_goes_back() {
+        assert_eq!(
+            transcript("\n3\nc\nb 2\nc\nc\nc\nq\n"),
+            vec![
+                "step 0: ? for help",
+                "step 1: ",
+                "step 4: ",
+                "step 5: breakpoint: 5 is a multiple of 5",
+                "step 3: back 2",
+                "step 5: breakpoint: 5 is a multiple of 5",
+                "step 10: breakpoint: 10 is a multiple of 5",
+                "step 12: finished",
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_at_the_start() {
+        assert_eq!(
+            transcript("b\nq\n"),
+            vec!["step 0: ? for help", "step 0: can't go back any further"]
+        );
+    }
+}
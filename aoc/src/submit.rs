@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use crate::{answers::Reason, fetch::Client};
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Rejected(Reason),
+    /// An answer went in too recently, with how long is left before another is taken.
+    Wait(Duration),
+    /// The part isn't open for answers, being either solved already or not yet unlocked.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site sends back.
+    pub fn parse(page: &str) -> Result<Self, String> {
+        let text = article(page);
+        if text.contains("That's the right answer") {
+            Ok(Verdict::Right)
+        } else if text.contains("That's not the right answer") {
+            Ok(Verdict::Rejected(if text.contains("too high") {
+                Reason::TooHigh
+            } else if text.contains("too low") {
+                Reason::TooLow
+            } else {
+                Reason::Wrong
+            }))
+        } else if text.contains("You gave an answer too recently") {
+            Ok(Verdict::Wait(left_to_wait(&text).unwrap_or_default()))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::WrongLevel)
+        } else {
+            Err(format!("could not make out the site's reply: {text}"))
+        }
+    }
+}
+
+/// The text of the page's `<article>`, which holds the message, without tags or runs of spaces.
+fn article(page: &str) -> String {
+    let body = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(page, |(_, rest)| rest);
+    let body = body.split_once("</article>").map_or(body, |(body, _)| body);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "You have 1m 5s left to wait" as a duration.
+fn left_to_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, left) = before.rsplit_once("have ")?;
+    left.split_whitespace()
+        .try_fold(Duration::ZERO, |total, amount| {
+            let unit = match amount.chars().last()? {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+            let count = amount[..amount.len() - 1].parse::<u64>().ok()?;
+            Some(total + Duration::from_secs(count * unit))
+        })
+}
+
+impl Client {
+    /// Sends `answer` for one part of a day and reads back the verdict.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+        let level = part.to_string();
+        let page = self.post(
+            &self.url(day, "answer"),
+            &[("level", &level), ("answer", answer)],
+            &format!("could not submit day {day} part {part}"),
+        )?;
+        Verdict::parse(&page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::{scratch, Server};
+    use rstest::rstest;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[rstest]
+    #[case(
+        "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+        Verdict::Right
+    )]
+    #[case(
+        "That's not the right answer; your answer is too high.  Please wait one minute.",
+        Verdict::Rejected(Reason::TooHigh)
+    )]
+    #[case(
+        "That's not the right answer; your answer is too low.",
+        Verdict::Rejected(Reason::TooLow)
+    )]
+    #[case(
+        "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+        Verdict::Rejected(Reason::Wrong)
+    )]
+    #[case(
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+        Verdict::Wait(Duration::from_secs(65))
+    )]
+    #[case(
+        "You don't seem to be solving the right level.  Did you already complete it?",
+        Verdict::WrongLevel
+    )]
+    fn reads_verdicts(#[case] message: &str, #[case] expected: Verdict) {
+        assert_eq!(Verdict::parse(&page(message)), Ok(expected));
+    }
+
+    #[test]
+    fn complains_about_strange_replies() {
+        let error = Verdict::parse(&page("<b>Something</b>   else")).unwrap_err();
+        assert!(error.ends_with(": Something else"), "{error}");
+    }
+
+    #[test]
+    fn posts_the_answer() {
+        let server = Server::start(|line, body| match (line, body) {
+            ("POST /2023/day/7/answer HTTP/1.1", "level=2&answer=5905") => {
+                (200, page("That's the right answer!"))
+            }
+            _ => (200, page("That's not the right answer.")),
+        });
+        let client = Client::new(&server.base, "abc", scratch("submit")).throttled(Duration::ZERO);
+
+        assert_eq!(client.submit(7, 2, "5905"), Ok(Verdict::Right));
+        assert_eq!(
+            client.submit(7, 2, "5904"),
+            Ok(Verdict::Rejected(Reason::Wrong))
+        );
+        assert_eq!(server.requests.lock().unwrap()[0].1, "session=abc");
+    }
+}
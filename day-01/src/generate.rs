@@ -0,0 +1,51 @@
+use rand::{seq::SliceRandom, Rng};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn digit(rng: &mut impl Rng) -> char {
+    char::from(b'0' + rng.gen_range(1..=9))
+}
+
+/// `size` calibration lines mixing letters, digits and spelled out digits.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.gen_range(1..=6) {
+            match rng.gen_range(0..3) {
+                0 => line.push(digit(rng)),
+                1 => line.push_str(WORDS.choose(rng).unwrap()),
+                _ => line.push(char::from(rng.gen_range(b'a'..=b'z'))),
+            }
+        }
+        // part one only sees real digits, so every line needs one
+        line.insert(rng.gen_range(0..=line.len()), digit(rng));
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn every_line_reads_as_a_two_digit_number() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 50);
+            let lines = parse(&input).unwrap();
+            assert_eq!(lines.len(), 50);
+            // digits run from one to nine, so neither end is ever a zero
+            for line in lines {
+                for value in [part1::process(&[line]), part2::process(&[line])] {
+                    assert!((11..=99).contains(&value.unwrap()), "{line}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use aoc_core::{
+    parsers::{eol, expect, lines},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{character::complete::alphanumeric1, sequence::terminated};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+/// Splits the input into calibration lines, which may only hold letters and digits.
+pub fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+    let calibration = terminated(alphanumeric1, expect("a letter or digit", eol));
+    Source::new(Day01::DAY, input).run(lines(calibration), input)
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        part2::process(input)
+    }
+}
+
+impl Generate for Day01 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
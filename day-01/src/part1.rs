@@ -0,0 +1,56 @@
+use aoc_core::{Solution, Unsolvable};
+
+use crate::Day01;
+
+pub fn process(lines: &[&str]) -> Result<u32, Unsolvable> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, calib)| {
+            let numbers: String = calib
+                .split("")
+                .filter(|char| {
+                    matches!(
+                        *char,
+                        "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9"
+                    )
+                })
+                .collect();
+            let (Some(firstnum), Some(lastnum)) = (numbers.chars().next(), numbers.chars().last())
+            else {
+                let reason = format!("line {} has no digits", i + 1);
+                return Err(Unsolvable::new(Day01::DAY, reason));
+            };
+
+            let mut number = String::new();
+            number.push(firstnum);
+            number.push(lastnum);
+            Ok(number.parse::<u32>().expect("two digits make a number"))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    #[test]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/01/example-1.txt")).unwrap());
+        assert_eq!(result, Ok(142));
+    }
+
+    #[test]
+    fn reports_line_without_digits() {
+        let err = process(
+            &parse(
+                "a1b
+xyz
+",
+            )
+            .unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(err.reason, "line 2 has no digits");
+    }
+}
@@ -1,14 +1,13 @@
-fn main() {
-    let input = include_str!("./input-1.txt");
-    let result = part2(input);
-    dbg!(result);
-}
+use aoc_core::{Solution, Unsolvable};
+
+use crate::Day01;
 
-fn part2(input: &str) -> u32 {
-    let result = input
-        .lines()
-        .map(|calib| {
-            let mut sliced = calib;
+pub fn process(lines: &[&str]) -> Result<u32, Unsolvable> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, calib)| {
+            let mut sliced = *calib;
             let mut numbers = String::new();
             while !sliced.is_empty() {
                 if sliced.starts_with("one") {
@@ -38,25 +37,27 @@ fn part2(input: &str) -> u32 {
                 sliced = &sliced[1..]
             }
 
-            let firstnum = numbers.chars().next().unwrap();
-            let lastnum = numbers.chars().last().unwrap();
+            let (Some(firstnum), Some(lastnum)) = (numbers.chars().next(), numbers.chars().last())
+            else {
+                let reason = format!("line {} has no digits, written or spelled out", i + 1);
+                return Err(Unsolvable::new(Day01::DAY, reason));
+            };
 
             let mut number = String::new();
             number.push(firstnum);
             number.push(lastnum);
-            return number.parse::<u32>().unwrap();
+            Ok(number.parse::<u32>().expect("two digits make a number"))
         })
-        .sum();
-    return result;
+        .sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse;
     #[test]
     fn it_works() {
-        let result = part2(include_str!("./input-2-test.txt"));
-        println!("{}", result.to_string());
-        assert_eq!(result, 281);
+        let result = process(&parse(include_str!("../../examples/01/example-2.txt")).unwrap());
+        assert_eq!(result, Ok(281));
     }
 }
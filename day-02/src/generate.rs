@@ -0,0 +1,47 @@
+use rand::{seq::SliceRandom, Rng};
+
+/// `size` games of up to six rounds, each drawing up to 20 cubes of one to three colours.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut out = String::new();
+    for id in 1..=size {
+        let rounds = (0..rng.gen_range(1..=6))
+            .map(|_| {
+                let mut colours = ["red", "green", "blue"];
+                colours.shuffle(rng);
+                colours[..rng.gen_range(1..=3)]
+                    .iter()
+                    .map(|colour| format!("{} {colour}", rng.gen_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        out.push_str(&format!("Game {id}: {}\n", rounds.join("; ")));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2, Cubes};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn draws_up_to_twenty_of_each_colour() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 50);
+            let games = parse(&input).unwrap();
+            let ids = games.iter().map(|game| game.id).collect::<Vec<_>>();
+            assert_eq!(ids, (1..=50).collect::<Vec<_>>());
+            for game in &games {
+                assert!(game.rounds.iter().all(|round| round != &Cubes::default()));
+                let fewest = game.min_cubes();
+                assert!([fewest.red, fewest.green, fewest.blue]
+                    .iter()
+                    .all(|&n| n <= 20));
+            }
+            assert!(part1::process(&games) <= ids.iter().sum());
+            assert!(part2::process(&games) <= 50 * 20 * 20 * 20);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use aoc_core::{
+    parsers::{expect, header, integer, lines, tag, IResult},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{
+    branch::alt,
+    combinator::{cut, map},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day02;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Cubes {
+    pub red: u32,
+    pub blue: u32,
+    pub green: u32,
+}
+
+impl Cubes {
+    pub fn expand(&mut self, cubes: &Cubes) {
+        self.red = self.red.max(cubes.red);
+        self.blue = self.blue.max(cubes.blue);
+        self.green = self.green.max(cubes.green);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Cubes>,
+}
+
+impl Game {
+    /// The fewest cubes of each colour that could have played every round.
+    pub fn min_cubes(&self) -> Cubes {
+        self.rounds.iter().fold(Cubes::default(), |mut acc, round| {
+            acc.expand(round);
+            acc
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    Source::new(Day02::DAY, input).run(lines(parse_game), input)
+}
+
+// Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+fn parse_game(input: &str) -> IResult<'_, Game> {
+    let id = preceded(tag("Game "), expect("a game id", integer));
+    let rounds = separated_list1(tag("; "), cut(parse_cubes));
+    map(header(id, rounds), |(id, rounds)| Game { id, rounds })(input)
+}
+
+// 3 blue, 4 red
+fn parse_cubes(input: &str) -> IResult<'_, Cubes> {
+    let color = expect(
+        "red, green or blue",
+        alt((tag("red"), tag("green"), tag("blue"))),
+    );
+    let draw = separated_pair(expect("a cube count", integer::<u32>), tag(" "), color);
+    let (rest, draws) = separated_list1(tag(", "), cut(draw))(input)?;
+
+    let mut cubes = Cubes::default();
+    for (value, color) in draws {
+        match color {
+            "red" => cubes.red += value,
+            "blue" => cubes.blue += value,
+            _ => cubes.green += value,
+        }
+    }
+    Ok((rest, cubes))
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day02 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_create_cubes() {
+        let cubes = Cubes::default();
+        assert_eq!(
+            Cubes {
+                red: 0,
+                blue: 0,
+                green: 0
+            },
+            cubes
+        );
+    }
+
+    #[test]
+    fn can_expand_bag() {
+        let mut cubes = Cubes {
+            red: 4,
+            blue: 1,
+            green: 2,
+        };
+        let compare_cubes = Cubes {
+            red: 1,
+            blue: 5,
+            green: 2,
+        };
+        cubes.expand(&compare_cubes);
+        assert_eq!(
+            cubes,
+            Cubes {
+                red: 4,
+                blue: 5,
+                green: 2
+            }
+        );
+    }
+
+    #[test]
+    fn can_parse_str_to_cubes() {
+        let cubes = parse_cubes("3 blue, 4 red");
+        assert_eq!(
+            cubes,
+            Ok((
+                "",
+                Cubes {
+                    red: 4,
+                    blue: 3,
+                    green: 0
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn reports_unknown_color() {
+        let err = parse("Game 1: 3 blue\nGame 2: 1 red, 2 purple").unwrap_err();
+        assert_eq!(err, ParseError::new(2, 2, 18, "red, green or blue"));
+    }
+}
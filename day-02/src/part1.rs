@@ -0,0 +1,42 @@
+use crate::{Cubes, Game};
+
+impl Cubes {
+    fn fits_into(&self, max: &Cubes) -> bool {
+        self.red <= max.red && self.blue <= max.blue && self.green <= max.green
+    }
+}
+
+pub fn process(games: &[Game]) -> u32 {
+    let cubes_bag = Cubes {
+        red: 12,
+        blue: 14,
+        green: 13,
+    };
+    possible_games(games, &cubes_bag)
+}
+
+fn possible_games(games: &[Game], comp_cubes: &Cubes) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.min_cubes().fits_into(comp_cubes))
+        .map(|game| game.id)
+        .sum::<u32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works() {
+        let cubes_bag = Cubes {
+            red: 12,
+            blue: 14,
+            green: 13,
+        };
+        let games = parse(include_str!("../../examples/02/example.txt")).unwrap();
+        let result = possible_games(&games, &cubes_bag);
+        assert_eq!(result, 8);
+    }
+}
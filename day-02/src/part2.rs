@@ -0,0 +1,27 @@
+use crate::{Cubes, Game};
+
+impl Cubes {
+    fn power(&self) -> u32 {
+        self.red * self.blue * self.green
+    }
+}
+
+pub fn process(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|game| game.min_cubes().power())
+        .sum::<u32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works() {
+        let games = parse(include_str!("../../examples/02/example.txt")).unwrap();
+        let result = process(&games);
+        assert_eq!(result, 2286);
+    }
+}
@@ -0,0 +1,56 @@
+use rand::{seq::SliceRandom, Rng};
+
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+/// A `size` by `size` schematic of part numbers, with the odd symbol between them.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut row = Vec::with_capacity(size);
+        while row.len() < size {
+            let room = size - row.len();
+            if rng.gen_bool(0.15) {
+                let digits = rng.gen_range(1..=3).min(room);
+                let number =
+                    rng.gen_range(10_u32.pow(digits as u32 - 1)..10_u32.pow(digits as u32));
+                row.extend(number.to_string().bytes());
+                // keep the next number from running into this one
+                if row.len() < size {
+                    row.push(b'.');
+                }
+            } else if rng.gen_bool(0.1) {
+                row.push(*SYMBOLS.choose(rng).unwrap());
+            } else {
+                row.push(b'.');
+            }
+        }
+        out.push_str(std::str::from_utf8(&row).expect("schematic is ascii"));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn keeps_numbers_apart() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 40);
+            let schematic = parse(&input).unwrap();
+            // numbers are written with at most three digits, so a longer one ran into another
+            assert!(schematic.part_numbers.iter().all(|part| part.number < 1000));
+            let total = schematic
+                .part_numbers
+                .iter()
+                .map(|part| part.number as u64)
+                .sum::<u64>();
+            assert!(part1::process(&schematic) <= total);
+            let gears = schematic.symbols.iter().filter(|s| s.symbol == '*').count();
+            assert!(part2::process(&schematic) as usize <= gears * 999 * 999);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use aoc_core::{Generate, Grid, ParseError, Solution, Source, Tile, Unsolvable};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day03;
+
+/// One character of the engine schematic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Digit(u32),
+    Symbol(char),
+}
+
+impl Tile for Cell {
+    const EXPECTED: &'static str = "a digit, '.' or a symbol";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Cell::Empty),
+            c if c.is_ascii_digit() => c.to_digit(10).map(Cell::Digit),
+            c if c.is_ascii_graphic() => Some(Cell::Symbol(c)),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Digit(d) => char::from_digit(*d, 10).unwrap(),
+            Cell::Symbol(c) => *c,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartNumber {
+    pub number: u32,
+    pub position: (usize, usize),
+    /// Positions of the symbols touching any digit, diagonals included.
+    pub adjacent: Vec<(usize, usize)>,
+}
+
+#[derive(Debug)]
+pub struct Symbol {
+    pub symbol: char,
+    pub position: (usize, usize),
+}
+
+pub struct Schematic {
+    pub part_numbers: Vec<PartNumber>,
+    pub symbols: Vec<Symbol>,
+}
+
+pub fn is_valid_part_number(part_number: &PartNumber, symbol: &Symbol) -> bool {
+    part_number.adjacent.contains(&symbol.position)
+}
+
+pub fn parse(input: &str) -> Result<Schematic, ParseError> {
+    let grid = Grid::<Cell>::parse(&Source::new(Day03::DAY, input), input)?;
+
+    let mut part_numbers: Vec<PartNumber> = Vec::new();
+    let mut symbols: Vec<Symbol> = Vec::new();
+
+    let mut current_number: Option<PartNumber> = None;
+
+    for (position, cell) in grid.iter() {
+        // numbers never wrap onto the next row
+        if position.0 == 0 {
+            part_numbers.extend(current_number.take());
+        }
+        match *cell {
+            Cell::Digit(digit) => {
+                let part_num = current_number.get_or_insert(PartNumber {
+                    number: 0,
+                    position,
+                    adjacent: Vec::new(),
+                });
+                part_num.number = part_num.number * 10 + digit;
+                for neighbour in grid.neighbours8(position.0, position.1) {
+                    if matches!(grid.get(neighbour.0, neighbour.1), Some(Cell::Symbol(_)))
+                        && !part_num.adjacent.contains(&neighbour)
+                    {
+                        part_num.adjacent.push(neighbour);
+                    }
+                }
+            }
+            Cell::Symbol(symbol) => {
+                symbols.push(Symbol { symbol, position });
+                part_numbers.extend(current_number.take());
+            }
+            Cell::Empty => part_numbers.extend(current_number.take()),
+        }
+    }
+    part_numbers.extend(current_number);
+
+    Ok(Schematic {
+        part_numbers,
+        symbols,
+    })
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+    type Answer1 = u64;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day03 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn check_is_valid_part_number() {
+        let schematic = parse("234.\n...a").unwrap();
+        let part_number = &schematic.part_numbers[0];
+        assert_eq!(part_number.number, 234);
+        assert!(is_valid_part_number(part_number, &schematic.symbols[0]));
+    }
+
+    #[rstest]
+    fn ignores_distant_symbols() {
+        let schematic = parse("12..\n...*").unwrap();
+        assert!(!is_valid_part_number(
+            &schematic.part_numbers[0],
+            &schematic.symbols[0]
+        ));
+    }
+
+    #[rstest]
+    fn range_test() {
+        assert!((0..2).contains(&1))
+    }
+
+    #[rstest]
+    fn reports_unexpected_character() {
+        let err = parse("467..\n..\t*.").err().unwrap();
+        assert_eq!(err, ParseError::new(3, 2, 3, "a digit, '.' or a symbol"));
+    }
+}
@@ -0,0 +1,44 @@
+use log::debug;
+
+use crate::{is_valid_part_number, Schematic};
+
+pub fn process(schematic: &Schematic) -> u64 {
+    let res = schematic
+        .part_numbers
+        .iter()
+        .filter(|part_num| {
+            schematic
+                .symbols
+                .iter()
+                .any(|symbol| is_valid_part_number(part_num, symbol))
+        })
+        .map(|part_num| u64::from(part_num.number))
+        .collect::<Vec<u64>>();
+
+    debug!(
+        "{} of {} numbers are part numbers",
+        res.len(),
+        schematic.part_numbers.len()
+    );
+    res.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use rstest::rstest;
+
+    #[rstest]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/03/example.txt")).unwrap());
+        assert_eq!(result, 4361);
+    }
+
+    #[rstest]
+    fn gets_correct_number_of_part_numbers() {
+        let input = include_str!("../../examples/03/example.txt");
+        let result = process(&parse(input).unwrap());
+        assert_eq!(result, 4361);
+    }
+}
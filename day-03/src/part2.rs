@@ -0,0 +1,39 @@
+use log::debug;
+
+use crate::{is_valid_part_number, PartNumber, Schematic};
+
+pub fn process(schematic: &Schematic) -> u32 {
+    let res = &schematic
+        .symbols
+        .iter()
+        .filter(|x| x.symbol == '*')
+        .filter_map(|symbol| {
+            let a = schematic
+                .part_numbers
+                .iter()
+                .filter(|part_num| is_valid_part_number(part_num, symbol))
+                .collect::<Vec<&PartNumber>>();
+            if a.len() == 2 {
+                Some(a.iter().fold(1_u32, |acc, x| acc * x.number))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<u32>>();
+
+    debug!("{} gears", res.len());
+    res.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use rstest::rstest;
+
+    #[rstest]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/03/example.txt")).unwrap());
+        assert_eq!(result, 467835);
+    }
+}
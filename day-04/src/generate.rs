@@ -0,0 +1,75 @@
+use rand::{
+    seq::{index, SliceRandom},
+    Rng,
+};
+
+/// Copies of a card past which it stops winning more, so the pile can still be counted.
+const MAX_COPIES: u64 = 10_000;
+
+/// `size` scratchcards of five winning numbers and eight numbers, never winning copies of
+/// cards past the end of the table.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut copies = vec![1; size];
+    let mut out = String::new();
+    for card in 0..size {
+        // 13 distinct numbers from 1 to 99: the winning ones first, then the ones that miss
+        let picks = index::sample(rng, 99, 13)
+            .into_iter()
+            .map(|i| i + 1)
+            .collect::<Vec<_>>();
+        let (winning, misses) = picks.split_at(5);
+
+        let mut matches = rng.gen_range(0..=5.min(size - card - 1));
+        if rng.gen_bool(0.5) || copies[card] > MAX_COPIES {
+            matches = 0;
+        }
+        for later in card + 1..=card + matches {
+            copies[later] += copies[card];
+        }
+
+        let mut numbers = winning[..matches].to_vec();
+        numbers.extend(&misses[..8 - matches]);
+        numbers.shuffle(rng);
+
+        let column = |numbers: &[usize]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        out.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            card + 1,
+            column(winning),
+            column(&numbers)
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn never_wins_cards_past_the_end() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 200);
+            let cards = parse(&input).unwrap();
+            assert_eq!(cards.len(), 200);
+            for (i, card) in cards.iter().enumerate() {
+                assert!(
+                    i + (card.matches() as usize) < cards.len(),
+                    "card {}",
+                    i + 1
+                );
+            }
+            // every original card is counted before any copies it wins
+            assert!(part1::process(&cards) >= 200);
+            assert!(part2::process(&cards) <= 200 * 16);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use aoc_core::{
+    parsers::{expect, header, integer, lines, tag, IResult},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{
+    character::complete::space1,
+    combinator::map,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day04;
+
+#[derive(Debug)]
+pub struct Card {
+    pub winning_numbers: HashSet<u32>,
+    pub numbers: Vec<u32>,
+}
+
+impl Card {
+    /// How many of the card's numbers are winning numbers.
+    pub fn matches(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|x| self.winning_numbers.contains(x))
+            .fold(0_u32, |acc, _| acc + 1)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    Source::new(Day04::DAY, input).run(lines(parse_card), input)
+}
+
+// Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+fn parse_card(input: &str) -> IResult<'_, Card> {
+    let id = preceded(
+        tuple((tag("Card"), space1)),
+        expect("a card number", integer::<u32>),
+    );
+    let numbers = || separated_list1(space1, integer::<u32>);
+    let bar = expect("\"|\"", tuple((space1, tag("|"), space1)));
+    let card = header(id, separated_pair(numbers(), bar, numbers()));
+    map(card, |(_, (winning_numbers, numbers))| Card {
+        winning_numbers: winning_numbers.into_iter().collect(),
+        numbers,
+    })(input)
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day04 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_missing_separator() {
+        let err = parse("Card 1: 41 48 | 83 86\nCard 2: 13 32 61 30").unwrap_err();
+        assert_eq!(err, ParseError::new(4, 2, 20, "\"|\""));
+    }
+}
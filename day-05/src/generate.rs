@@ -0,0 +1,72 @@
+use rand::{seq::SliceRandom, Rng};
+
+const STAGES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// An almanac over the numbers below `1000 * size`, where every map shuffles a handful of
+/// ranges around and four seed ranges cover up to a tenth of the numbers each.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let span = 1000 * size.max(1) as u64;
+
+    let seeds = (0..4)
+        .flat_map(|_| {
+            let length = rng.gen_range(1..=span / 10);
+            [rng.gen_range(0..=span - length), length]
+        })
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>();
+    let mut out = format!("seeds: {}\n", seeds.join(" "));
+
+    for stage in STAGES {
+        // cut the numbers into ranges, then lay them back down in a different order
+        let mut cuts = (0..rng.gen_range(1..=5))
+            .map(|_| rng.gen_range(1..span))
+            .collect::<Vec<_>>();
+        cuts.extend([0, span]);
+        cuts.sort_unstable();
+        cuts.dedup();
+        let mut ranges = cuts
+            .windows(2)
+            .map(|w| (w[0], w[1] - w[0]))
+            .collect::<Vec<_>>();
+        ranges.shuffle(rng);
+
+        out.push_str(&format!("\n{stage} map:\n"));
+        let mut to = 0;
+        for (from, length) in ranges {
+            out.push_str(&format!("{to} {from} {length}\n"));
+            to += length;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn every_map_shuffles_the_numbers() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 5);
+            let almanac = parse(&input).unwrap();
+            assert_eq!(almanac.stages.len(), 7);
+            for stage in &almanac.stages {
+                let mut moved = (0..5000).map(|n| stage.convert(n)).collect::<Vec<_>>();
+                moved.sort_unstable();
+                assert_eq!(moved, (0..5000).collect::<Vec<_>>(), "seed {seed}");
+            }
+            assert!(part1::process(&almanac) < 5000);
+            assert!(part2::process(&almanac).unwrap() < 5000);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use aoc_core::{
+    parsers::{
+        blank_line, blocks, expect, header, integer, integers, lines, tag, Expected, IResult,
+    },
+    Generate, IntervalSet, ParseError, PiecewiseMap, Solution, Source, Unsolvable,
+};
+use nom::{
+    bytes::complete::take_while1,
+    character::complete::{line_ending, space1},
+    combinator::{map, opt},
+    sequence::{pair, preceded, terminated, tuple},
+    Err,
+};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day05;
+
+#[derive(Debug, PartialEq)]
+pub struct CategoryMapper {
+    to: u64,
+    from: u64,
+    range: u64,
+}
+
+impl CategoryMapper {
+    pub fn new(to: u64, from: u64, range: u64) -> Self {
+        Self { from, to, range }
+    }
+}
+
+/// One map of the almanac, where the first mapper covering a value wins.
+#[derive(Debug, PartialEq)]
+pub struct CategoryMapperStage {
+    map: PiecewiseMap,
+}
+
+impl CategoryMapperStage {
+    /// `None` if a mapper's source range runs past the largest `u64`.
+    pub fn new(items: Vec<CategoryMapper>) -> Option<Self> {
+        let mut map = PiecewiseMap::new();
+        for item in items {
+            map.insert(item.from..item.from.checked_add(item.range)?, item.to);
+        }
+        Some(Self { map })
+    }
+
+    pub fn convert(&self, value: u64) -> u64 {
+        self.map.get(value)
+    }
+
+    /// Converts every value in the set at once.
+    pub fn convert_set(&self, values: &IntervalSet) -> IntervalSet {
+        self.map.map(values)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub stages: Vec<CategoryMapperStage>,
+}
+
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    // seeds: 79 14 55 13
+    let seeds = map(
+        header(tag("seeds"), integers("a seed number")),
+        |(_, seeds)| seeds,
+    );
+    let stages = opt(preceded(blank_line, blocks(parse_stage)));
+    let source = Source::new(Day05::DAY, input);
+    let (seeds, stages) = source.run(pair(seeds, stages), input)?;
+
+    Ok(Almanac {
+        seeds,
+        stages: stages.unwrap_or_default(),
+    })
+}
+
+// seed-to-soil map:
+// 50 98 2
+fn parse_stage(input: &str) -> IResult<'_, CategoryMapperStage> {
+    let name = take_while1(|c: char| c.is_ascii_lowercase() || c == '-');
+    let title = terminated(name, pair(tag(" map:"), line_ending));
+
+    let number = || expect("a number", preceded(space1, integer));
+    let mapper = map(tuple((integer, number(), number())), |(to, from, range)| {
+        CategoryMapper::new(to, from, range)
+    });
+    let (rest, (name, items)) = pair(title, lines(mapper))(input)?;
+    let stage = CategoryMapperStage::new(items)
+        .ok_or_else(|| Err::Failure(Expected::new(name, "a map whose ranges fit in a u64")))?;
+    Ok((rest, stage))
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        part2::process(input)
+    }
+}
+
+impl Generate for Day05 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeds_parser() {
+        let result = parse("seeds: 79 14 55 13").unwrap();
+        assert_eq!(result.seeds, vec![79, 14, 55, 13]);
+    }
+
+    #[test]
+    fn reports_range_past_u64() {
+        let err =
+            parse("seeds: 79\n\nseed-to-soil map:\n50 98 2\n0 2 18446744073709551615").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(5, 3, 1, "a map whose ranges fit in a u64")
+        );
+    }
+
+    #[test]
+    fn test_category_mapper_parser() {
+        let result = parse(
+            "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50 48",
+        )
+        .unwrap();
+        let ans = CategoryMapperStage::new(vec![
+            CategoryMapper::new(50, 98, 2),
+            CategoryMapper::new(52, 50, 48),
+        ]);
+        assert_eq!(Some(result.stages), ans.map(|ans| vec![ans]));
+    }
+
+    #[test]
+    fn test_category_mapper_stage_convert() {
+        let seed_to_soil = CategoryMapperStage::new(vec![
+            CategoryMapper::new(50, 98, 2),
+            CategoryMapper::new(52, 50, 48),
+        ])
+        .unwrap();
+
+        let ans = [79, 14, 55, 13]
+            .iter()
+            .map(|x| seed_to_soil.convert(*x))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![81, 14, 57, 13], ans);
+    }
+
+    #[test]
+    fn reports_short_mapping() {
+        let err = parse("seeds: 79\n\nseed-to-soil map:\n50 98\n").unwrap_err();
+        assert_eq!(err, ParseError::new(5, 4, 6, "a number"));
+    }
+}
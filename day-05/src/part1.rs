@@ -0,0 +1,32 @@
+use crate::Almanac;
+
+fn process1(almanac: &Almanac) -> Vec<u64> {
+    almanac
+        .stages
+        .iter()
+        .fold(almanac.seeds.clone(), |acc, stage| {
+            acc.iter().map(|y| stage.convert(*y)).collect::<Vec<_>>()
+        })
+}
+
+pub fn process(almanac: &Almanac) -> u64 {
+    *process1(almanac).iter().min().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works() {
+        let result = process1(&parse(include_str!("../../examples/05/example.txt")).unwrap());
+        assert_eq!(result, vec![82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn takes_an_odd_number_of_seeds() {
+        let result = process(&parse("seeds: 79 14 55").unwrap());
+        assert_eq!(result, 14);
+    }
+}
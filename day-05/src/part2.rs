@@ -0,0 +1,154 @@
+use core::ops::Range;
+
+use aoc_core::{IntervalSet, Solution, Unsolvable};
+use log::debug;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{Almanac, Day05};
+
+#[derive(Debug)]
+struct SeedRange {
+    start: u64,
+    range: u64,
+}
+
+impl SeedRange {
+    fn get_range_iter(&self) -> Range<u64> {
+        self.start..(self.start + self.range)
+    }
+}
+
+fn seed_ranges(seeds: &[u64]) -> Vec<SeedRange> {
+    seeds
+        .chunks(2)
+        .map(|i| SeedRange {
+            start: i[0],
+            range: i[1],
+        })
+        .collect::<Vec<_>>()
+}
+
+pub fn process(almanac: &Almanac) -> Result<u64, Unsolvable> {
+    // the seeds are read as start and length pairs
+    if almanac.seeds.len() % 2 == 1 {
+        return Err(Unsolvable::new(
+            Day05::DAY,
+            "the last seed range has no length",
+        ));
+    }
+    let seed_ranges = seed_ranges(&almanac.seeds);
+    if let Some(i) = seed_ranges
+        .iter()
+        .position(|seeds| seeds.start.checked_add(seeds.range).is_none())
+    {
+        let reason = format!("seed range {} runs past a u64", i + 1);
+        return Err(Unsolvable::new(Day05::DAY, reason));
+    }
+
+    debug!("seed ranges: {seed_ranges:?}");
+
+    // each seed range is followed through the stages on its own, so with the `parallel`
+    // feature they share out across threads
+    #[cfg(feature = "parallel")]
+    let seed_ranges = seed_ranges.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let seed_ranges = seed_ranges.iter();
+
+    seed_ranges
+        .map(|seeds| IntervalSet::from(seeds.get_range_iter()))
+        .filter_map(|seeds| lowest_location(almanac, seeds))
+        .min()
+        .ok_or_else(|| Unsolvable::new(Day05::DAY, "every seed range is empty"))
+}
+
+/// Pushes the whole set of seeds through each stage rather than every seed one at a time.
+fn lowest_location(almanac: &Almanac, seeds: IntervalSet) -> Option<u64> {
+    let locations = almanac.stages.iter().fold(seeds, |values, stage| {
+        let values = stage.convert_set(&values);
+        debug!("{} ranges after stage", values.ranges().len());
+        values
+    });
+    locations.min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate::generate, parse, CategoryMapper, CategoryMapperStage};
+    use proptest::prelude::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Tries every seed in every range, which is slow but plainly right.
+    fn every_seed(almanac: &Almanac) -> Option<u64> {
+        seed_ranges(&almanac.seeds)
+            .iter()
+            .flat_map(SeedRange::get_range_iter)
+            .map(|seed| {
+                almanac
+                    .stages
+                    .iter()
+                    .fold(seed, |acc, stage| stage.convert(acc))
+            })
+            .min()
+    }
+
+    #[test]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/05/example.txt")).unwrap());
+        assert_eq!(result, Ok(46));
+        let result = every_seed(&parse(include_str!("../../examples/05/example.txt")).unwrap());
+        assert_eq!(result, Some(46));
+    }
+
+    #[test]
+    fn reports_empty_seed_ranges() {
+        let err = process(&parse("seeds: 79 0 55 0").unwrap()).unwrap_err();
+        assert_eq!(err.reason, "every seed range is empty");
+    }
+
+    #[test]
+    fn reports_unpaired_seed() {
+        let err = process(&parse("seeds: 79 14 55").unwrap()).unwrap_err();
+        assert_eq!(err.reason, "the last seed range has no length");
+    }
+
+    #[test]
+    fn reports_seed_range_past_u64() {
+        let err = process(&parse("seeds: 79 14 2 18446744073709551615").unwrap()).unwrap_err();
+        assert_eq!(err.reason, "seed range 2 runs past a u64");
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn ranges_match_every_seed(seed: u64, size in 1_usize..5) {
+            let input = generate(&mut StdRng::seed_from_u64(seed), size);
+            let almanac = parse(&input).unwrap();
+            prop_assert_eq!(process(&almanac).ok(), every_seed(&almanac));
+        }
+
+        /// Maps that overlap each other and leave gaps, unlike the generated ones.
+        #[test]
+        fn ranges_match_every_seed_with_ragged_maps(
+            seeds in prop::collection::vec((0_u64..200, 1_u64..50), 1..4),
+            stages in prop::collection::vec(
+                prop::collection::vec((0_u64..300, 0_u64..300, 1_u64..100), 0..4),
+                1..5,
+            ),
+        ) {
+            let almanac = Almanac {
+                seeds: seeds.into_iter().flat_map(|(start, range)| [start, range]).collect(),
+                stages: stages
+                    .into_iter()
+                    .map(|items| {
+                        let items = items.into_iter().map(|(to, from, range)| CategoryMapper::new(to, from, range));
+                        CategoryMapperStage::new(items.collect()).unwrap()
+                    })
+                    .collect(),
+            };
+            prop_assert_eq!(process(&almanac).ok(), every_seed(&almanac));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use rand::Rng;
+
+/// Largest distance that holding the button for part of `time` can reach.
+fn record(time: u64) -> u64 {
+    time / 2 * (time - time / 2)
+}
+
+fn join(numbers: &[u64]) -> u64 {
+    numbers
+        .iter()
+        .fold(0, |acc, n| acc * 10_u64.pow(n.to_string().len() as u32) + n)
+}
+
+/// `size` races of up to 99ms whose records can be beaten, read either way.
+///
+/// There are at most four, as part two runs the whole sheet as one long race.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.clamp(1, 4);
+    loop {
+        let times = (0..size)
+            .map(|_| rng.gen_range(7..=99))
+            .collect::<Vec<u64>>();
+        let distances = times
+            .iter()
+            .map(|&time| rng.gen_range(record(time) / 2..record(time)))
+            .collect::<Vec<_>>();
+
+        // part two reads the sheet as one race, which has to be winnable too
+        if join(&distances) < record(join(&times)) {
+            let column = |numbers: &[u64]| {
+                numbers
+                    .iter()
+                    .map(|n| format!(" {n:>4}"))
+                    .collect::<String>()
+            };
+            return format!(
+                "Time:   {}\nDistance:{}\n",
+                column(&times),
+                column(&distances)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part2, Race};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn record_is_the_furthest_a_race_goes() {
+        for time in 7..=99 {
+            // an even race peaks at one hold, an odd one at the two either side of half
+            let race = Race {
+                time,
+                distance: record(time) - 1,
+            };
+            assert_eq!(race.ways_to_win(), 1 + time % 2);
+            let race = Race {
+                time,
+                distance: record(time),
+            };
+            assert_eq!(race.ways_to_win(), 0);
+        }
+    }
+
+    #[test]
+    fn every_race_can_be_won() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 3);
+            let races = parse(&input).unwrap();
+            assert_eq!(races.len(), 3);
+            for race in &races {
+                assert!((1..race.time).contains(&race.ways_to_win()), "{race:?}");
+            }
+            assert!(part2::process(&races) > 0);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use aoc_core::{
+    parsers::{header, integers, tag},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use log::debug;
+use nom::{character::complete::line_ending, combinator::consumed, sequence::separated_pair};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day06;
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    /// How many whole milliseconds of holding the button beat the record.
+    ///
+    /// Holding for `hold` goes `hold * (time - hold)`, so the winning holds lie strictly
+    /// between the roots of `hold² - time * hold + distance`, symmetric about `time / 2`.
+    pub fn ways_to_win(&self) -> u64 {
+        let beats = |hold: u64| hold * (self.time - hold) > self.distance;
+        let half = self.time / 2;
+        if !beats(half) {
+            return 0;
+        }
+
+        // the float root can be off by one either way for big races, so nudge it into place
+        let root = (self.time as f64
+            - (self.time.pow(2) as f64 - 4.0 * self.distance as f64).sqrt())
+            / 2.0;
+        let mut first = (root.max(0.0) as u64).min(half);
+        while first > 0 && beats(first - 1) {
+            first -= 1;
+        }
+        while !beats(first) {
+            first += 1;
+        }
+
+        let ways = self.time - 2 * first + 1;
+        debug!("{self:?}: {ways} ways to win");
+        ways
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+    let source = Source::new(Day06::DAY, input);
+    let time = header(tag("Time"), integers::<u64>("a number"));
+    let distance = consumed(header(tag("Distance"), integers::<u64>("a number")));
+    let ((_, time), (distance_line, (_, distance))) =
+        source.run(separated_pair(time, line_ending, distance), input)?;
+    if time.len() != distance.len() {
+        return Err(source.error_after(distance_line, format!("{} distances", time.len())));
+    }
+
+    Ok(time
+        .into_iter()
+        .zip(distance)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<Race>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day06 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn ways_to_win_matches_trying_every_hold(time in 0_u64..2000, distance in 0_u64..1_000_000) {
+            let race = Race { time, distance };
+            let tried = (0..time).filter(|hold| hold * (time - hold) > distance).count();
+            prop_assert_eq!(race.ways_to_win(), tried as u64);
+        }
+    }
+
+    #[test]
+    fn reports_missing_distance() {
+        let err = parse("Time:      7  15   30\nDistance:  9  40").unwrap_err();
+        assert_eq!(err, ParseError::new(6, 2, 17, "3 distances"));
+    }
+}
@@ -0,0 +1,17 @@
+use crate::Race;
+
+pub fn process(races: &[Race]) -> u64 {
+    races.iter().map(Race::ways_to_win).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/06/example.txt")).unwrap());
+        assert_eq!(result, 288);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::Race;
+
+pub fn process(races: &[Race]) -> u64 {
+    kerned(races).ways_to_win()
+}
+
+/// Reads the race sheet again, ignoring the spaces between numbers.
+fn kerned(races: &[Race]) -> Race {
+    fn join(numbers: impl Iterator<Item = u64>) -> u64 {
+        numbers.fold(0_u64, |acc, x| {
+            let digits = x.checked_ilog10().unwrap_or(0) + 1;
+            acc * 10_u64.pow(digits) + x
+        })
+    }
+
+    Race {
+        time: join(races.iter().map(|x| x.time)),
+        distance: join(races.iter().map(|x| x.distance)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/06/example.txt")).unwrap());
+        assert_eq!(result, 71503);
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+const CARDS: &[u8] = b"AKQJT98765432";
+
+/// `size` different hands, capped at every possible hand, each bidding up to 1000.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.min(CARDS.len().pow(5));
+    let mut hands = HashSet::new();
+    let mut out = String::new();
+    while hands.len() < size {
+        let hand = (0..5)
+            .map(|_| char::from(*CARDS.choose(rng).unwrap()))
+            .collect::<String>();
+        if hands.insert(hand.clone()) {
+            out.push_str(&format!("{hand} {}\n", rng.gen_range(1..=1000)));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn jokers_only_matter_when_dealt() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 100);
+            let plays = parse(&input).unwrap();
+            let hands = plays.iter().map(|play| play.cards).collect::<HashSet<_>>();
+            assert_eq!(hands.len(), 100);
+
+            let without_jacks = input
+                .lines()
+                .filter(|line| !line.contains('J'))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let plays = parse(&without_jacks).unwrap();
+            assert_eq!(part1::process(&plays), part2::process(&plays));
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_core::{
+    parsers::{expect, integer, lines, tag, IResult},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{
+    character::complete::one_of,
+    combinator::{map, recognize},
+    multi::count,
+    sequence::separated_pair,
+};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day07;
+
+/// One line of the puzzle: a hand of five cards and what it bids.
+#[derive(Debug, PartialEq)]
+pub struct Play<'a> {
+    pub cards: &'a str,
+    pub bid: u32,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Play<'_>>, ParseError> {
+    Source::new(Day07::DAY, input).run(lines(parse_play), input)
+}
+
+// 32T3K 765
+fn parse_play(input: &str) -> IResult<'_, Play<'_>> {
+    let cards = recognize(count(expect("a card", one_of("AKQJT98765432")), 5));
+    let play = separated_pair(cards, tag(" "), expect("a bid", integer));
+    map(play, |(cards, bid)| Play { cards, bid })(input)
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<Play<'a>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day07 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_unknown_card() {
+        let err = parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!(err, ParseError::new(7, 2, 4, "a card"));
+    }
+}
@@ -1,9 +1,8 @@
-use std::{cmp::Ordering, collections::HashMap, vec};
+use std::{cmp::Ordering, collections::HashMap};
 
-fn main() {
-    let input = include_str!("./input.txt");
-    println!("Result: {:?}", process(input));
-}
+use log::trace;
+
+use crate::Play;
 
 #[derive(Debug, PartialEq, PartialOrd)]
 enum HandType {
@@ -17,9 +16,9 @@ enum HandType {
 }
 
 #[derive(Debug)]
-struct Hand {
+struct Hand<'a> {
     hand_type: HandType,
-    cards: &'static str,
+    cards: &'a str,
     score: u32,
 }
 
@@ -33,31 +32,22 @@ impl From<&str> for HandType {
 
         let jacks = letter_counts.remove(&'J').unwrap_or(0);
 
-        let mut specialvar = letter_counts.iter().map(|(_, y)| *y).collect::<Vec<_>>();
+        let mut specialvar = letter_counts.values().copied().collect::<Vec<_>>();
         specialvar.sort_by(|a, b| b.cmp(a));
 
         match specialvar.len() {
             0 => specialvar.push(jacks),
-            _ => specialvar[0] = specialvar[0] + jacks,
+            _ => specialvar[0] += jacks,
         }
 
-        let x = specialvar.iter().fold(
-            (
-                u8::from(0),
-                u8::from(0),
-                u8::from(0),
-                u8::from(0),
-                u8::from(0),
-            ),
-            |acc, x| match acc {
-                (0, 0, 0, 0, 0) => (*x, 0, 0, 0, 0),
-                (a, 0, 0, 0, 0) => (a, *x, 0, 0, 0),
-                (a, b, 0, 0, 0) => (a, b, *x, 0, 0),
-                (a, b, c, 0, 0) => (a, b, c, *x, 0),
-                (a, b, c, d, 0) => (a, b, c, d, *x),
-                _ => panic!(),
-            },
-        );
+        let x = specialvar.iter().fold((0, 0, 0, 0, 0), |acc, x| match acc {
+            (0, 0, 0, 0, 0) => (*x, 0, 0, 0, 0),
+            (a, 0, 0, 0, 0) => (a, *x, 0, 0, 0),
+            (a, b, 0, 0, 0) => (a, b, *x, 0, 0),
+            (a, b, c, 0, 0) => (a, b, c, *x, 0),
+            (a, b, c, d, 0) => (a, b, c, d, *x),
+            _ => panic!(),
+        });
 
         match x {
             (1, 1, 1, 1, 1) => HandType::HighCard,
@@ -92,8 +82,7 @@ impl Rank for char {
 fn raw_compare(left: &str, right: &str) -> Ordering {
     let x = left
         .chars()
-        .into_iter()
-        .zip(right.chars().into_iter())
+        .zip(right.chars())
         .filter_map(|(left, right)| {
             let x = left.rank().partial_cmp(&right.rank());
             match x {
@@ -110,8 +99,8 @@ fn raw_compare(left: &str, right: &str) -> Ordering {
     }
 }
 
-impl PartialEq for Hand {
-    fn eq(&self, other: &Self) -> bool {
+impl PartialEq for Hand<'_> {
+    fn eq(&self, _other: &Self) -> bool {
         // self.iter().zip(other.iter()).find(|(left, right)| {});
         true
     }
@@ -137,19 +126,13 @@ impl PartialEq for Hand {
 //     }
 // }
 
-fn process(input: &'static str) -> u32 {
-    let mut hands = input
-        .lines()
-        .map(|line| {
-            let mut it = line.split(" ");
-            let cards = it.next().unwrap();
-            let score = it.next().unwrap().parse::<_>().unwrap();
-            let hand_type = HandType::from(cards);
-            Hand {
-                hand_type,
-                cards,
-                score,
-            }
+pub fn process(plays: &[Play]) -> u32 {
+    let mut hands = plays
+        .iter()
+        .map(|play| Hand {
+            hand_type: HandType::from(play.cards),
+            cards: play.cards,
+            score: play.bid,
         })
         .collect::<Vec<_>>();
     hands.sort_by(|a, b| {
@@ -166,24 +149,23 @@ fn process(input: &'static str) -> u32 {
         .enumerate()
         .fold::<u32, _>(0, |acc, (i, hand)| {
             let modified_score = (u32::try_from(i).unwrap() + 1) * hand.score;
-            println!("{:?}", modified_score);
+            trace!("{hand:?} scores {modified_score}");
             modified_score + acc
         });
 
-    dbg!(&hands);
     result
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse;
     use rstest::rstest;
 
     #[rstest]
     fn it_works() {
-        let input = include_str!("./input-test.txt");
-        dbg!(input);
-        let result = process(input);
+        let input = include_str!("../../examples/07/example.txt");
+        let result = process(&parse(input).unwrap());
         assert_eq!(result, 5905);
     }
 
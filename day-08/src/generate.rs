@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+const PRIMES: [usize; 12] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Six ghosts following `size` instructions round loops of prime multiples of that many
+/// steps, as in the real puzzle; the ghost from `AAA` is the walk that part one takes.
+///
+/// Node names run out past 60 instructions, so `size` stops there.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let loops = PRIMES.choose_multiple(rng, 6).copied().collect::<Vec<_>>();
+    network(rng, size.clamp(1, 60), &loops)
+}
+
+/// A map where the ghost starting from the `i`th start reaches its end node after
+/// `instructions * loops[i]` steps, and every time as many steps after that.
+fn network<R: Rng>(rng: &mut R, instructions: usize, loops: &[usize]) -> String {
+    let turns = (0..instructions)
+        .map(|_| if rng.gen() { 'L' } else { 'R' })
+        .collect::<String>();
+
+    let mut used = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+    let mut name = |rng: &mut R, last: &[u8]| loop {
+        let name = [
+            rng.gen_range(b'A'..=b'Z'),
+            rng.gen_range(b'A'..=b'Z'),
+            *last.choose(rng).unwrap(),
+        ];
+        let name = String::from_utf8(name.to_vec()).unwrap();
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+    let middle = (b'B'..=b'Y').collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    for (ghost, &factor) in loops.iter().enumerate() {
+        let length = instructions * factor;
+        let mut path = Vec::with_capacity(length + 1);
+        path.push(match ghost {
+            0 => "AAA".to_string(),
+            _ => name(rng, b"A"),
+        });
+        for _ in 1..length {
+            path.push(name(rng, &middle));
+        }
+        path.push(match ghost {
+            0 => "ZZZ".to_string(),
+            _ => name(rng, b"Z"),
+        });
+
+        // leaving the end node is the same instruction as leaving the start, so it goes
+        // round again from the first step; the turn not taken goes anywhere on the loop
+        for (step, node) in path.iter().enumerate() {
+            let next = match step {
+                _ if step == length => &path[1],
+                _ => &path[step + 1],
+            };
+            let other = path.choose(rng).unwrap();
+            let (left, right) = match turns.as_bytes()[step % instructions] {
+                b'L' => (next, other),
+                _ => (other, next),
+            };
+            lines.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+    lines.shuffle(rng);
+
+    format!("{turns}\n\n{}\n", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn ghosts_meet_after_every_loop() {
+        for seed in 0..5 {
+            let input = network(&mut StdRng::seed_from_u64(seed), 11, &[3, 5, 7]);
+            let map = parse(&input).unwrap();
+            assert_eq!(part1::process(&map), Ok(33));
+            assert_eq!(part2::process(&map), Ok(11 * 3 * 5 * 7));
+        }
+    }
+
+    #[test]
+    fn ghosts_loop_in_whole_runs_of_instructions() {
+        for seed in 0..5 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            let map = parse(&input).unwrap();
+            // AAA's loop is a prime number of runs through the 20 instructions
+            let alone = part1::process(&map).unwrap() as usize;
+            assert_eq!(alone % 20, 0);
+            assert!(PRIMES.contains(&(alone / 20)));
+            assert_eq!(part2::process(&map).unwrap() % alone as u64, 0);
+        }
+    }
+}
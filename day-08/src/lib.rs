@@ -0,0 +1,127 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{
+    parsers::{blank_line, eol, expect, lines, tag, IResult},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{
+    bytes::complete::take_while1,
+    character::complete::alphanumeric1,
+    combinator::map,
+    sequence::{delimited, separated_pair, terminated},
+};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day08;
+
+#[derive(Debug, PartialEq)]
+pub struct DirectionMap<'a> {
+    pub left: &'a str,
+    pub right: &'a str,
+}
+
+#[derive(Debug)]
+pub struct DesertMap<'a> {
+    /// The left/right instructions, only ever `L` or `R`.
+    pub instructions: &'a str,
+    pub nodes: HashMap<&'a str, DirectionMap<'a>>,
+}
+
+pub fn parse(input: &str) -> Result<DesertMap<'_>, ParseError> {
+    let instructions = expect(
+        "'L' or 'R'",
+        terminated(take_while1(|c| matches!(c, 'L' | 'R')), eol),
+    );
+    let source = Source::new(Day08::DAY, input);
+    let (instructions, nodes) = source.run(
+        separated_pair(instructions, blank_line, lines(node_parser)),
+        input,
+    )?;
+
+    let names = nodes.iter().map(|(name, _)| *name).collect::<HashSet<_>>();
+    let mut links = nodes.iter().flat_map(|(_, node)| [node.left, node.right]);
+    if let Some(name) = links.find(|name| !names.contains(name)) {
+        return Err(source.error(name, "a node defined on its own line"));
+    }
+    let nodes = nodes.into_iter().collect();
+
+    Ok(DesertMap {
+        instructions,
+        nodes,
+    })
+}
+
+// AAA = (BBB, CCC)
+fn node_parser(input: &str) -> IResult<'_, (&str, DirectionMap<'_>)> {
+    let pair = delimited(
+        tag("("),
+        separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+        tag(")"),
+    );
+    let node = separated_pair(alphanumeric1, tag(" = "), pair);
+    map(node, |(name, (left, right))| {
+        (name, DirectionMap { left, right })
+    })(input)
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = DesertMap<'a>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        part2::process(input)
+    }
+}
+
+impl Generate for Day08 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_unknown_instruction() {
+        let err = parse("LRX\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!(err, ParseError::new(8, 1, 3, "'L' or 'R'"));
+    }
+
+    #[test]
+    fn reports_unclosed_node() {
+        let err = parse("LR\n\nAAA = (BBB, CCC)\nBBB = (AAA, ZZZ").unwrap_err();
+        assert_eq!(err, ParseError::new(8, 4, 16, "\")\""));
+    }
+
+    #[test]
+    fn reports_undefined_node() {
+        let err = parse(
+            "LR
+
+AAA = (BBB, AAA)
+BBB = (AAA, ZZZ)",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(8, 4, 13, "a node defined on its own line")
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use aoc_core::{Solution, Unsolvable};
+use log::{debug, trace};
+
+use crate::{Day08, DesertMap};
+
+pub fn process(map: &DesertMap) -> Result<u32, Unsolvable> {
+    let _instructions = map.instructions;
+    let desert_map = &map.nodes;
+
+    // let ans = _instructions.chars().fold(("AAA", 1), |acc, x| {
+    //     if acc.0 == "ZZZ" {
+    //         return acc;
+    //     };
+    //     let next_node = match x {
+    //         'L' => desert_map.get(acc.0).unwrap().left,
+    //         'R' => desert_map.get(acc.0).unwrap().right,
+    //         _ => panic!("Unknown direction"),
+    //     };
+    //     (next_node, acc.1 + 1)
+    // });
+
+    let __instructions = _instructions.chars().collect::<Vec<_>>();
+    debug!("{} instructions", __instructions.len());
+
+    if !desert_map.contains_key("AAA") {
+        return Err(Unsolvable::new(Day08::DAY, "there is no node AAA"));
+    }
+
+    // every (node, instruction) state is visited at most once on the way to ZZZ
+    let limit = desert_map.len() * __instructions.len();
+    let mut curr = "AAA";
+    let mut step = 0;
+    while curr != "ZZZ" {
+        if step > limit {
+            return Err(Unsolvable::new(Day08::DAY, "ZZZ can't be reached from AAA"));
+        }
+        curr = match __instructions[step % __instructions.len()] {
+            'L' => desert_map[curr].left,
+            'R' => desert_map[curr].right,
+            _ => panic!("Unknown direction"),
+        };
+        step += 1;
+        trace!("{step} -> {curr}");
+    }
+
+    step.try_into()
+        .map_err(|_| Unsolvable::new(Day08::DAY, "the walk doesn't fit in a u32"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works_1() {
+        let result = process(
+            &parse(
+                "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn it_works_2() {
+        let result = process(
+            &parse(
+                "LLRLLRLLRLLRLLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn reports_unreachable_end() {
+        let missing = parse("L\n\nBBB = (BBB, BBB)").unwrap();
+        assert_eq!(
+            process(&missing),
+            Err(Unsolvable::new(8, "there is no node AAA"))
+        );
+
+        let cut_off = parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(
+            process(&cut_off),
+            Err(Unsolvable::new(8, "ZZZ can't be reached from AAA"))
+        );
+    }
+}
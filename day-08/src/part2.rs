@@ -2,28 +2,11 @@ use core::panic;
 use std::collections::HashMap;
 use std::fmt::Formatter;
 
-fn main() {
-    let input = include_str!("./input-1.txt");
-    println!("Result: {:?}", part1(input));
-}
-
-#[derive(Debug)]
-struct DirectionMap {
-    left: &'static str,
-    right: &'static str,
-}
-
-fn node_parser(input: &'static str) -> (&str, DirectionMap) {
-    let (first, other) = input.split_once(" = (").unwrap();
-    let (left, rest) = other.split_once(", ").unwrap();
-    let (right, _) = rest.split_once(")").unwrap();
-    let paths = DirectionMap { left, right };
-    (first, paths)
-}
+use aoc_core::{Solution, Unsolvable};
+use aoc_math::lcm_all;
+use log::debug;
 
-fn check_nodes_end_in_z(input: &Vec<&str>) -> bool {
-    input.iter().all(|x| x.ends_with("Z"))
-}
+use crate::{Day08, DesertMap, DirectionMap};
 
 fn get_nodes_end_in_a<'a>(input: &Vec<&'a str>) -> Vec<&'a str> {
     input
@@ -35,7 +18,11 @@ fn get_nodes_end_in_a<'a>(input: &Vec<&'a str>) -> Vec<&'a str> {
         .collect::<Vec<_>>()
 }
 
-fn follow_map<'a>(input: &str, instruction: char, map: &HashMap<&'a str, DirectionMap>) -> &'a str {
+fn follow_map<'a>(
+    input: &str,
+    instruction: char,
+    map: &HashMap<&'a str, DirectionMap<'a>>,
+) -> &'a str {
     match instruction {
         'L' => map.get(input).unwrap().left,
         'R' => map.get(input).unwrap().right,
@@ -43,13 +30,13 @@ fn follow_map<'a>(input: &str, instruction: char, map: &HashMap<&'a str, Directi
     }
 }
 
-struct SuccessLoc {
-    loc: &'static str,
+struct SuccessLoc<'a> {
+    loc: &'a str,
     step: usize,
     ptr: usize,
 }
 
-impl core::fmt::Debug for SuccessLoc {
+impl core::fmt::Debug for SuccessLoc<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
@@ -59,45 +46,26 @@ impl core::fmt::Debug for SuccessLoc {
     }
 }
 
-fn have_i_been_here_before(s_l: &SuccessLoc, vec: &Vec<SuccessLoc>) -> bool {
+fn have_i_been_here_before(s_l: &SuccessLoc, vec: &[SuccessLoc]) -> bool {
     vec.iter().any(|x| x.loc == s_l.loc && x.ptr == s_l.ptr)
 }
 
-pub fn lcm(nums: &[usize]) -> usize {
-    match nums {
-        [a] => *a,
-        [a, b @ ..] => {
-            let b_star = lcm(&b);
-            a * b_star / gcd(*a, b_star)
-        }
-        _ => panic!("lcm called with empty list"),
-    }
-}
+pub fn process(map: &DesertMap) -> Result<u64, Unsolvable> {
+    let instr_str = map.instructions;
+    let desert_map = &map.nodes;
 
-fn gcd(a: usize, b: usize) -> usize {
-    match b == 0 {
-        true => a,
-        false => gcd(b, a % b),
-    }
-}
-
-fn part1(input: &'static str) -> u64 {
-    let (instr_str, nodes) = input.split_once("\n\n").unwrap();
-
-    let desert_map = nodes
-        .lines()
-        .map(|node| node_parser(node))
-        .collect::<HashMap<_, _>>();
-
-    let all_locs = desert_map.keys().map(|x| *x).collect::<Vec<_>>();
+    let all_locs = desert_map.keys().copied().collect::<Vec<_>>();
 
     let start_locs = get_nodes_end_in_a(&all_locs);
 
-    dbg!(&start_locs);
+    debug!("starting from {start_locs:?}");
+    if start_locs.is_empty() {
+        return Err(Unsolvable::new(Day08::DAY, "no node ends in A"));
+    }
 
     let instructions = instr_str.chars().collect::<Vec<_>>();
-
-    dbg!("canary");
+    // past this many steps every (node, instruction) state has come round again
+    let limit = desert_map.len() * instructions.len();
 
     let vec_of_interest = start_locs
         .iter()
@@ -107,10 +75,10 @@ fn part1(input: &'static str) -> u64 {
             let mut curr_step = 0;
             let mut curr_ptr = 0;
             let mut curr_loc = *start_loc;
-            loop {
+            while curr_step <= limit {
                 // do step then add to vec
                 let instruction = instructions[curr_ptr];
-                curr_loc = follow_map(curr_loc, instruction, &desert_map);
+                curr_loc = follow_map(curr_loc, instruction, desert_map);
                 let next_el = SuccessLoc {
                     loc: curr_loc,
                     step: curr_step + 1,
@@ -140,43 +108,54 @@ fn part1(input: &'static str) -> u64 {
             let steps_group = vec_of_interest[1..]
                 .iter()
                 .map(|path| {
-                    match path
-                        .iter()
+                    path.iter()
                         .find(|other_state| other_state.ptr == state_of_interest.ptr)
-                    {
-                        Some(s) => Some(s.step),
-                        None => None,
-                    }
+                        .map(|s| s.step)
                 })
                 .collect::<Option<Vec<_>>>();
 
             match steps_group {
                 Some(mut steps) => {
                     steps.push(state_of_interest.step);
-                    Some(lcm(steps.as_slice()))
+                    lcm_all(steps.into_iter().map(|step| step as i128))
                 }
                 None => None,
             }
         })
         .min()
-        .unwrap();
+        .ok_or_else(|| Unsolvable::new(Day08::DAY, "the ghosts never stand on Z nodes together"))?;
 
-    shortest_walk.try_into().unwrap()
+    shortest_walk
+        .try_into()
+        .map_err(|_| Unsolvable::new(Day08::DAY, "the walk doesn't fit in a u64"))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse;
 
     #[test]
-    fn test_lcm() {
-        let result = lcm(&[5, 8, 12]);
-        assert_eq!(result, 120);
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/08/example-3.txt")).unwrap());
+        assert_eq!(result, Ok(6));
     }
 
     #[test]
-    fn test_lc_2() {
-        let result = lcm(&[18023, 21251, 15871, 16409, 14257, 11567]);
-        assert_eq!(result, 11678319315857);
+    fn reports_ghosts_that_never_meet() {
+        let no_start = parse("L\n\nBBZ = (BBZ, BBZ)").unwrap();
+        assert_eq!(
+            process(&no_start),
+            Err(Unsolvable::new(8, "no node ends in A"))
+        );
+
+        let stuck = parse("L\n\n11A = (11A, 11Z)\n11Z = (11Z, 11Z)").unwrap();
+        assert_eq!(
+            process(&stuck),
+            Err(Unsolvable::new(
+                8,
+                "the ghosts never stand on Z nodes together"
+            ))
+        );
     }
 }
@@ -0,0 +1,61 @@
+use rand::Rng;
+
+/// `size` histories of 21 readings, each following a polynomial of degree at most five.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        // build the difference table from the bottom up: a constant row, then each
+        // row above starts somewhere and climbs by the row below it
+        let mut row = vec![rng.gen_range(-5..=5); 21];
+        for _ in 0..rng.gen_range(0..=5) {
+            let mut reading: i32 = rng.gen_range(-10..=10);
+            row = row
+                .iter()
+                .map(|step| {
+                    let current = reading;
+                    reading += step;
+                    current
+                })
+                .collect();
+        }
+        let readings = row.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        out.push_str(&readings.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn extrapolates_the_readings_it_left_out() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 50);
+            let histories = parse(&input).unwrap();
+            assert!(histories.iter().all(|history| history.len() == 21));
+
+            // twenty readings pin down a polynomial of degree five, so the one dropped
+            // from either end comes back
+            let without_last = histories
+                .iter()
+                .map(|h| h[..20].to_vec())
+                .collect::<Vec<_>>();
+            let without_first = histories
+                .iter()
+                .map(|h| h[1..].to_vec())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                part1::process(&without_last).unwrap(),
+                histories.iter().map(|h| h[20]).sum::<i32>()
+            );
+            assert_eq!(
+                part2::process(&without_first).unwrap(),
+                histories.iter().map(|h| h[0]).sum::<i32>()
+            );
+        }
+    }
+}
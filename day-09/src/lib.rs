@@ -0,0 +1,86 @@
+use aoc_core::{
+    parsers::{integers, lines},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use aoc_math::extrapolate;
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day09;
+
+/// One history of readings per line.
+pub fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    Source::new(Day09::DAY, input).run(lines(integers("a reading")), input)
+}
+
+/// The reading at `index` of a history carried on both ways, `0` being its first reading,
+/// or `None` if it doesn't fit in an `i32`.
+pub fn extend(history: &[i32], index: i128) -> Option<i32> {
+    let samples = history.iter().map(|&x| x as i128).collect::<Vec<_>>();
+    extrapolate(&samples, index).and_then(|x| i32::try_from(x).ok())
+}
+
+/// Adds up the reading each history is extended to, failing on any that runs past an `i32`.
+fn sum_extended(
+    histories: &[Vec<i32>],
+    extended: impl Fn(&[i32]) -> Option<i32>,
+) -> Result<i32, Unsolvable> {
+    histories
+        .iter()
+        .enumerate()
+        .try_fold(0_i32, |sum, (i, history)| {
+            extended(history)
+                .and_then(|reading| sum.checked_add(reading))
+                .ok_or_else(|| {
+                    Unsolvable::new(Day09::DAY, format!("history {} runs past an i32", i + 1))
+                })
+        })
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        part2::process(input)
+    }
+}
+
+impl Generate for Day09 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_bad_reading() {
+        let err = parse("0 3 6\n1 3 x 10").unwrap_err();
+        assert_eq!(err, ParseError::new(9, 2, 5, "a reading"));
+    }
+
+    #[test]
+    fn reports_readings_past_an_i32() {
+        let histories = parse("1 2 3\n0 1000000000 2000000000").unwrap();
+        let why = Unsolvable::new(9, "history 2 runs past an i32");
+        assert_eq!(part1::process(&histories), Err(why));
+        assert_eq!(part2::process(&histories), Ok(-1000000000));
+    }
+}
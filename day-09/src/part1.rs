@@ -0,0 +1,19 @@
+use aoc_core::Unsolvable;
+
+use crate::{extend, sum_extended};
+
+pub fn process(histories: &[Vec<i32>]) -> Result<i32, Unsolvable> {
+    sum_extended(histories, |ints| extend(ints, ints.len() as i128))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/09/example.txt")).unwrap());
+        assert_eq!(result, Ok(114));
+    }
+}
@@ -0,0 +1,24 @@
+use aoc_core::Unsolvable;
+use log::debug;
+
+use crate::{extend, sum_extended};
+
+pub fn process(histories: &[Vec<i32>]) -> Result<i32, Unsolvable> {
+    sum_extended(histories, |ints| {
+        let prev = extend(ints, -1)?;
+        debug!("{ints:?}: {prev}");
+        Some(prev)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/09/example.txt")).unwrap());
+        assert_eq!(result, Ok(2));
+    }
+}
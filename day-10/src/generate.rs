@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use aoc_core::{outline, Direction, Tile};
+use rand::{seq::SliceRandom, Rng};
+
+use crate::Pipe;
+
+const JUNK: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+/// A pipe loop round a random tree-shaped region of `size` by `size` rooms, with stray
+/// pipes scattered inside and out.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let points = outline(rng, size);
+    sketch(rng, &points)
+}
+
+/// The pipe joining the ways into and out of a tile, in either order.
+fn pipe(a: Direction, b: Direction) -> Pipe {
+    use Direction::*;
+    match (a, b) {
+        (North, South) | (South, North) => Pipe::NorthSouth,
+        (East, West) | (West, East) => Pipe::EastWest,
+        (North, East) | (East, North) => Pipe::NorthEast,
+        (North, West) | (West, North) => Pipe::NorthWest,
+        (South, East) | (East, South) => Pipe::SouthEast,
+        (South, West) | (West, South) => Pipe::SouthWest,
+        _ => unreachable!("the loop never doubles back"),
+    }
+}
+
+fn towards(from: (i64, i64), to: (i64, i64)) -> Direction {
+    Direction::ALL
+        .into_iter()
+        .find(|dir| dir.step(from) == to)
+        .expect("loop points are neighbours")
+}
+
+/// Draws the loop through `points`, one tile in from the edge, starting somewhere random.
+fn sketch(rng: &mut impl Rng, points: &[(i64, i64)]) -> String {
+    let side = points.iter().map(|&(x, y)| x.max(y)).max().unwrap_or(0) + 3;
+    let mut tiles = HashMap::new();
+    for (i, &at) in points.iter().enumerate() {
+        let before = points[(i + points.len() - 1) % points.len()];
+        let after = points[(i + 1) % points.len()];
+        let shifted = (at.0 + 1, at.1 + 1);
+        tiles.insert(shifted, pipe(towards(at, before), towards(at, after)));
+    }
+    let &start = points.choose(rng).expect("loop is never empty");
+    let start = (start.0 + 1, start.1 + 1);
+    tiles.insert(start, Pipe::Start);
+
+    let mut out = String::new();
+    for y in 0..side {
+        for x in 0..side {
+            let tile = match tiles.get(&(x, y)) {
+                Some(pipe) => pipe.to_char(),
+                // stray pipes beside the start could pass for the way in
+                None if Direction::ALL.iter().any(|dir| dir.step((x, y)) == start) => '.',
+                None if rng.gen_bool(0.5) => *JUNK.choose(rng).unwrap(),
+                None => '.',
+            };
+            out.push(tile);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn loop_encloses_what_it_should() {
+        for seed in 0..10 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let points = outline(&mut rng, 4);
+
+            // Pick's theorem gives the points strictly inside from the shoelace area
+            let twice_area = points
+                .iter()
+                .zip(points.iter().cycle().skip(1))
+                .map(|(a, b)| a.0 * b.1 - b.0 * a.1)
+                .sum::<i64>()
+                .abs();
+            let inside = (twice_area - points.len() as i64) / 2 + 1;
+
+            let sketch = parse(&sketch(&mut rng, &points)).unwrap();
+            assert_eq!(part1::process(&sketch) as usize, points.len() / 2);
+            assert_eq!(part2::process(&sketch) as i64, inside);
+        }
+    }
+
+    #[test]
+    fn start_is_on_a_closed_loop() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 6);
+            // parse follows the pipes out of the start and rejects a loop that breaks off
+            let sketch = parse(&input).unwrap();
+            let (pipe_loop, _) = part2::regions(&sketch);
+            assert!(pipe_loop.contains(&sketch.start));
+            assert_eq!(part1::process(&sketch) as usize, pipe_loop.len() / 2);
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use aoc_core::{
+    Animate, Direction, Generate, Grid, Image, ParseError, Solution, Source, Tile, Unsolvable,
+};
+use glam::u32::UVec2;
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod render;
+
+pub struct Day10;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Pipe {
+    Start,      // S
+    NorthEast,  // L
+    NorthSouth, // |
+    NorthWest,  // J
+    SouthEast,  // F
+    SouthWest,  // 7
+    EastWest,   // -
+    None,       // .
+}
+
+impl Pipe {
+    /// Sides of the tile the pipe opens onto.
+    fn openings(&self) -> &'static [Direction] {
+        use Direction::*;
+        match self {
+            Pipe::NorthEast => &[North, East],
+            Pipe::NorthSouth => &[North, South],
+            Pipe::NorthWest => &[North, West],
+            Pipe::SouthEast => &[South, East],
+            Pipe::SouthWest => &[South, West],
+            Pipe::EastWest => &[East, West],
+            Pipe::Start | Pipe::None => &[],
+        }
+    }
+
+    pub fn movable_by_dir(&self, dir: Direction) -> bool {
+        self == &Pipe::Start || self.openings().contains(&dir.reverse())
+    }
+
+    /// Heading out of this pipe after entering it heading `last_move`.
+    pub fn next_move(&self, last_move: Direction) -> Direction {
+        match self.openings() {
+            [a, b] if *a == last_move.reverse() => *b,
+            [a, b] if *b == last_move.reverse() => *a,
+            _ => panic!("Invalid move"),
+        }
+    }
+}
+
+impl Tile for Pipe {
+    const EXPECTED: &'static str = "a pipe, '.' or 'S'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Pipe::NorthEast),
+            '|' => Some(Pipe::NorthSouth),
+            'J' => Some(Pipe::NorthWest),
+            'F' => Some(Pipe::SouthEast),
+            '7' => Some(Pipe::SouthWest),
+            '-' => Some(Pipe::EastWest),
+            '.' => Some(Pipe::None),
+            'S' => Some(Pipe::Start),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Pipe::Start => 'S',
+            Pipe::NorthEast => 'L',
+            Pipe::NorthSouth => '|',
+            Pipe::NorthWest => 'J',
+            Pipe::SouthEast => 'F',
+            Pipe::SouthWest => '7',
+            Pipe::EastWest => '-',
+            Pipe::None => '.',
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Sketch {
+    pub grid: Grid<Pipe>,
+    pub start: UVec2,
+}
+
+#[derive(Debug)]
+pub struct Window<'a> {
+    pub north: &'a Pipe,
+    pub south: &'a Pipe,
+    pub east: &'a Pipe,
+    pub west: &'a Pipe,
+}
+
+impl Sketch {
+    pub fn get_curr_pipe(&self, pos: UVec2) -> &Pipe {
+        self.safe_get_pos(Some(pos.x), Some(pos.y))
+    }
+
+    pub fn get_window(&self, pos: UVec2) -> Window<'_> {
+        Window {
+            north: self.safe_get_pos(Some(pos.x), pos.y.checked_sub(1)),
+            east: self.safe_get_pos(Some(pos.x + 1), Some(pos.y)),
+            south: self.safe_get_pos(Some(pos.x), Some(pos.y + 1)),
+            west: self.safe_get_pos(pos.x.checked_sub(1), Some(pos.y)),
+        }
+    }
+
+    fn safe_get_pos(&self, x: Option<u32>, y: Option<u32>) -> &Pipe {
+        x.zip(y)
+            .and_then(|(x, y)| self.grid.get(x as usize, y as usize))
+            .unwrap_or(&Pipe::None)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Sketch, ParseError> {
+    let source = Source::new(Day10::DAY, input);
+    let grid = Grid::<Pipe>::parse(&source, input)?;
+
+    let start = grid
+        .position(|pipe| pipe == &Pipe::Start)
+        .ok_or_else(|| source.error_after(input, "a start tile 'S'"))?;
+    if let Some(((x, y), expected)) = broken_link(&grid, start) {
+        let line = input.lines().nth(y).unwrap_or_default();
+        return Err(source.error(&line[x..], expected));
+    }
+
+    let start = UVec2::new(start.0 as u32, start.1 as u32);
+    Ok(Sketch { grid, start })
+}
+
+/// Follows the loop out of `start` the way the solvers do, returning where it breaks off
+/// before getting back round, and what should have been there.
+fn broken_link(grid: &Grid<Pipe>, start: (usize, usize)) -> Option<((usize, usize), &'static str)> {
+    use Direction::*;
+    let enter = |(x, y): (usize, usize), heading: Direction| {
+        grid.step(x, y, heading).filter(|&(x, y)| {
+            grid.get(x, y)
+                .is_some_and(|pipe| pipe.movable_by_dir(heading))
+        })
+    };
+
+    let first = [North, East, South, West]
+        .into_iter()
+        .find_map(|heading| enter(start, heading).map(|next| (heading, next)));
+    let Some((mut heading, mut at)) = first else {
+        return Some((start, "a pipe connecting to 'S'"));
+    };
+    // every tile is on the loop at most once, so it closes within this many steps or not at all
+    for _ in 0..grid.width() * grid.height() {
+        let pipe = grid.get(at.0, at.1)?;
+        if pipe == &Pipe::Start {
+            return None;
+        }
+        heading = pipe.next_move(heading);
+        at = match enter(at, heading) {
+            Some(next) => next,
+            None => {
+                let past = grid.step(at.0, at.1, heading);
+                return Some(match past {
+                    Some(past) => (past, "a pipe joining the loop"),
+                    None => (at, "a pipe that stays on the grid"),
+                });
+            }
+        };
+    }
+    Some((at, "a loop back to 'S'"))
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Sketch;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day10 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+impl Animate for Day10 {
+    fn frames(input: &Self::Input<'_>) -> Vec<Image> {
+        render::frames(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_unknown_character() {
+        let err = parse(".....\n.S-7.\n.|x|.").unwrap_err();
+        assert_eq!(err, ParseError::new(10, 3, 3, "a pipe, '.' or 'S'"));
+    }
+
+    #[test]
+    fn reports_missing_start() {
+        let err = parse("F7\nLJ").unwrap_err();
+        assert_eq!(err, ParseError::new(10, 2, 3, "a start tile 'S'"));
+    }
+
+    #[test]
+    fn reports_broken_loop() {
+        let err = parse("...\n.S.\n...").unwrap_err();
+        assert_eq!(err, ParseError::new(10, 2, 2, "a pipe connecting to 'S'"));
+
+        let err = parse(".....\n.S-7.\n.|.|.\n.L-..\n.....").unwrap_err();
+        assert_eq!(err, ParseError::new(10, 4, 4, "a pipe joining the loop"));
+
+        let err = parse("S-\n|.").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(10, 1, 2, "a pipe that stays on the grid")
+        );
+    }
+}
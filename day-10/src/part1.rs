@@ -0,0 +1,107 @@
+use log::trace;
+
+use aoc_core::Direction;
+
+use crate::Sketch;
+
+pub fn process(sketch: &Sketch) -> u32 {
+    let mut curr_pos = sketch.start;
+
+    let mut last_dir: Option<Direction> = None;
+
+    let mut move_number: u32 = 0;
+
+    loop {
+        use Direction::*;
+
+        // get window
+        let window = sketch.get_window(curr_pos);
+
+        trace!("move {move_number} at {curr_pos}: {window:?}");
+        // see if there are valid moves (assume there is exactly one)
+        // can move up?
+
+        match last_dir {
+            None => {
+                if window.north.movable_by_dir(North) {
+                    curr_pos.y -= 1;
+                    last_dir = Some(North);
+                } else if window.east.movable_by_dir(East) {
+                    curr_pos.x += 1;
+                    last_dir = Some(East);
+                } else if window.south.movable_by_dir(South) {
+                    curr_pos.y += 1;
+                    last_dir = Some(South);
+                } else if window.west.movable_by_dir(West) {
+                    curr_pos.x -= 1;
+                    last_dir = Some(West);
+                } else {
+                    unreachable!("parse checks a pipe connects to the start");
+                }
+            }
+            Some(known_last_dir) => {
+                let curr_pipe = sketch.get_curr_pipe(curr_pos);
+                match curr_pipe.next_move(known_last_dir) {
+                    North => {
+                        curr_pos.y -= 1;
+                        last_dir = Some(North);
+                    }
+                    East => {
+                        curr_pos.x += 1;
+                        last_dir = Some(East);
+                    }
+                    South => {
+                        curr_pos.y += 1;
+                        last_dir = Some(South);
+                    }
+                    West => {
+                        curr_pos.x -= 1;
+                        last_dir = Some(West);
+                    }
+                }
+            }
+        }
+
+        move_number += 1;
+
+        if sketch.start == curr_pos {
+            break;
+        }
+    }
+    move_number / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works() {
+        let result = process(
+            &parse(
+                ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 4);
+    }
+    #[test]
+    fn it_works_2() {
+        let result = process(
+            &parse(
+                "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 8);
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use glam::u32::UVec2;
+use log::trace;
+
+use aoc_core::Direction;
+
+use crate::{Pipe, Sketch};
+
+impl Sketch {
+    fn render(&self) -> String {
+        self.grid
+            .rows()
+            .map(|row| {
+                let mut line = row
+                    .iter()
+                    .map(|pipe| match pipe {
+                        Pipe::Start => "S ",
+                        Pipe::NorthEast => "└─",
+                        Pipe::NorthSouth => "| ",
+                        Pipe::NorthWest => "┘ ",
+                        Pipe::SouthEast => "┌─",
+                        Pipe::SouthWest => "┐ ",
+                        Pipe::EastWest => "──",
+                        Pipe::None => ". ",
+                    })
+                    .collect::<String>();
+                line.push('\n');
+                line
+            })
+            .collect()
+    }
+}
+
+pub fn process(sketch: &Sketch) -> u32 {
+    let (_, enclosed) = regions(sketch);
+
+    trace!("sketch:\n{}", sketch.render());
+
+    enclosed.len() as u32
+}
+
+/// The tiles on the loop through the start, and the tiles the loop encloses.
+pub fn regions(sketch: &Sketch) -> (Vec<UVec2>, Vec<UVec2>) {
+    let mut curr_pos = sketch.start;
+
+    let mut last_dir: Option<Direction> = None;
+
+    let mut pos_history: HashMap<UVec2, (Direction, Pipe)> = HashMap::new();
+
+    loop {
+        use Direction::*;
+
+        // get window
+        let window = sketch.get_window(curr_pos);
+
+        // see if there are valid moves (assume there is exactly one)
+        // can move up?
+
+        let curr_pipe = *sketch.get_curr_pipe(curr_pos);
+
+        match last_dir {
+            None => {
+                if window.north.movable_by_dir(North) {
+                    last_dir = Some(North);
+                    pos_history.insert(curr_pos, (North, curr_pipe));
+                    curr_pos.y -= 1;
+                } else if window.east.movable_by_dir(East) {
+                    last_dir = Some(East);
+                    pos_history.insert(curr_pos, (East, curr_pipe));
+                    curr_pos.x += 1;
+                } else if window.south.movable_by_dir(South) {
+                    last_dir = Some(South);
+                    pos_history.insert(curr_pos, (South, curr_pipe));
+                    curr_pos.y += 1;
+                } else if window.west.movable_by_dir(West) {
+                    last_dir = Some(West);
+                    pos_history.insert(curr_pos, (West, curr_pipe));
+                    curr_pos.x -= 1;
+                } else {
+                    unreachable!("parse checks a pipe connects to the start");
+                }
+            }
+            Some(known_last_dir) => {
+                let curr_pipe = sketch.get_curr_pipe(curr_pos);
+                match curr_pipe.next_move(known_last_dir) {
+                    North => {
+                        last_dir = Some(North);
+                        pos_history.insert(curr_pos, (North, *curr_pipe));
+                        curr_pos.y -= 1;
+                    }
+                    East => {
+                        last_dir = Some(East);
+                        pos_history.insert(curr_pos, (East, *curr_pipe));
+                        curr_pos.x += 1;
+                    }
+                    South => {
+                        last_dir = Some(South);
+                        pos_history.insert(curr_pos, (South, *curr_pipe));
+                        curr_pos.y += 1;
+                    }
+                    West => {
+                        last_dir = Some(West);
+                        pos_history.insert(curr_pos, (West, *curr_pipe));
+                        curr_pos.x -= 1;
+                    }
+                }
+            }
+        }
+
+        if sketch.start == curr_pos {
+            break;
+        }
+    }
+
+    // the start tile has no shape of its own, so work out whether it connects north
+    // from the first move out of it and the last move back into it
+    let (first_dir, _) = pos_history[&sketch.start];
+    let start_goes_north = first_dir == Direction::North || last_dir == Some(Direction::South);
+
+    let history = &pos_history;
+    let enclosed = (0..sketch.grid.height())
+        .flat_map(|y| {
+            let mut inside = false;
+            (0..sketch.grid.width()).filter_map(move |x| {
+                let pos = UVec2::new(x as u32, y as u32);
+                match history.get(&pos) {
+                    // crossing a loop tile that connects north flips inside and outside
+                    Some((_, Pipe::NorthSouth | Pipe::NorthEast | Pipe::NorthWest)) => {
+                        inside = !inside;
+                        None
+                    }
+                    Some((_, Pipe::Start)) if start_goes_north => {
+                        inside = !inside;
+                        None
+                    }
+                    Some(_) => None,
+                    None => inside.then_some(pos),
+                }
+            })
+        })
+        .collect();
+
+    (pos_history.into_keys().collect(), enclosed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    //     #[test]
+    //     fn it_works() {
+    //         let result = process(
+    //             ".....
+    // .S-7.
+    // .|.|.
+    // .L-J.
+    // .....",
+    //         );
+    //         assert_eq!(result, 4);
+    //     }
+    //     #[test]
+    //     fn it_works_2() {
+    //         let result = process(
+    //             "7-F7-
+    // .FJ|7
+    // SJLL7
+    // |F--J
+    // LJ.LJ",
+    //         );
+    //         assert_eq!(result, 8);
+    //     }
+
+    // pt 2 test cases
+    #[test]
+    fn it_works_3() {
+        let result = process(
+            &parse(
+                "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 10);
+    }
+    #[test]
+    fn it_works_4() {
+        let result = process(
+            &parse(
+                ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 8);
+    }
+    #[test]
+    fn it_works_5() {
+        let result = process(
+            &parse(
+                "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 4);
+    }
+    #[test]
+    fn it_works_mod_1() {
+        let result = process(
+            &parse(
+                ".....
+.S-7.
+.|.|.
+.L-J.
+.....",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 1);
+    }
+    #[test]
+    fn it_works_mod_2() {
+        let result = process(
+            &parse(
+                "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 1);
+    }
+}
@@ -0,0 +1,70 @@
+use aoc_core::{Image, Paint, Rgb};
+use glam::u32::UVec2;
+
+use crate::{part2, Pipe, Sketch};
+
+const LOOP: Rgb = Rgb(250, 179, 135);
+const ENCLOSED: Rgb = Rgb(166, 227, 161);
+
+impl Paint for Pipe {
+    fn paint(&self) -> Rgb {
+        match self {
+            Pipe::None => Rgb(30, 30, 46),
+            Pipe::Start => Rgb(243, 139, 168),
+            _ => Rgb(88, 91, 112),
+        }
+    }
+}
+
+fn on_grid(pos: UVec2) -> (usize, usize) {
+    (pos.x as usize, pos.y as usize)
+}
+
+/// The sketch, then the loop through the start picked out, then the tiles it encloses.
+pub fn frames(sketch: &Sketch) -> Vec<Image> {
+    let (pipe_loop, enclosed) = part2::regions(sketch);
+    let base = Image::new(&sketch.grid);
+
+    let mut traced = base.clone();
+    traced.overlay(pipe_loop.into_iter().map(on_grid), LOOP);
+    let mut filled = traced.clone();
+    filled.overlay(enclosed.into_iter().map(on_grid), ENCLOSED);
+
+    vec![base, traced, filled]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1};
+
+    fn count(image: &Image, colour: Rgb) -> usize {
+        (0..image.height())
+            .flat_map(|y| (0..image.width()).map(move |x| (x, y)))
+            .filter(|&(x, y)| image.get(x, y) == Some(colour))
+            .count()
+    }
+
+    #[test]
+    fn overlays_the_loop_and_what_it_encloses() {
+        let sketch = parse(
+            "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........",
+        )
+        .unwrap();
+        let frames = frames(&sketch);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], Image::new(&sketch.grid));
+
+        let last = &frames[2];
+        assert_eq!(count(last, LOOP), 2 * part1::process(&sketch) as usize);
+        assert_eq!(count(last, ENCLOSED), part2::process(&sketch) as usize);
+    }
+}
@@ -0,0 +1,40 @@
+use rand::Rng;
+
+/// A `size` by `size` image with a galaxy in about one tile in twenty, and about one
+/// row and column in eight left empty to expand.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let empty_columns = (0..size).map(|_| rng.gen_ratio(1, 8)).collect::<Vec<_>>();
+    let mut out = String::new();
+    for _ in 0..size {
+        let empty_row = rng.gen_ratio(1, 8);
+        for &empty_column in &empty_columns {
+            let galaxy = !empty_row && !empty_column && rng.gen_ratio(1, 20);
+            out.push(if galaxy { '#' } else { '.' });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn distances_grow_with_the_expansion() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 40);
+            let image = parse(&input).unwrap();
+            assert_eq!((image.width(), image.height()), (40, 40));
+
+            // each empty line between two galaxies adds one more step per extra copy, so
+            // going from two copies to a million adds 999_998 steps per crossing
+            let (doubled, million) = (part1::process(&image) as u64, part2::process(&image));
+            let crossings = (million - doubled) / 999_998;
+            assert_eq!((million - doubled) % 999_998, 0);
+            assert!(doubled >= crossings);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use aoc_core::{Generate, Grid, ParseError, Solution, Source, Tile, Unsolvable};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day11;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Space {
+    Galaxy,
+    Empty,
+}
+
+impl Tile for Space {
+    const EXPECTED: &'static str = "'.' or '#'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Space::Empty),
+            '#' => Some(Space::Galaxy),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Space::Galaxy => '#',
+            Space::Empty => '.',
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Grid<Space>, ParseError> {
+    Grid::parse(&Source::new(Day11::DAY, input), input)
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Grid<Space>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day11 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_unknown_space() {
+        let err = parse("...#\n.*..").unwrap_err();
+        assert_eq!(err, ParseError::new(11, 2, 2, "'.' or '#'"));
+    }
+}
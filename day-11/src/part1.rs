@@ -0,0 +1,108 @@
+use std::collections::{BTreeSet, HashSet};
+
+use glam::UVec2;
+use log::{debug, trace};
+
+use aoc_core::{Grid, Tile};
+
+use crate::{parse, Space};
+
+pub fn process(starmap: &Grid<Space>) -> u32 {
+    trace!("starmap:\n{starmap}");
+
+    let mut filled_cols: BTreeSet<u32> = BTreeSet::new();
+    let mut filled_rows: BTreeSet<u32> = BTreeSet::new();
+
+    starmap.iter().for_each(|((x, y), space)| {
+        if let Space::Galaxy = space {
+            filled_cols.insert(x as u32);
+            filled_rows.insert(y as u32);
+        }
+    });
+    let starmap_width = starmap.width() as u32;
+
+    let expanded_starmap_width = starmap_width * 2 - filled_cols.len() as u32;
+
+    let empty_row = (0..expanded_starmap_width).map(|_| ".").collect::<String>();
+
+    let expanded_map_str = starmap
+        .rows()
+        .enumerate()
+        .map(|(y, line)| {
+            let mut n = line
+                .iter()
+                .enumerate()
+                .map(|(x, space)| match filled_cols.contains(&(x as u32)) {
+                    true => space.to_char().to_string(),
+                    false => "..".to_string(),
+                })
+                .collect::<String>();
+            n.push('\n');
+            if !filled_rows.contains(&(y as u32)) {
+                n.push_str(empty_row.as_str());
+                n.push('\n');
+            }
+            n
+        })
+        .collect::<String>();
+
+    let expanded_map = parse(&expanded_map_str).expect("expanded map only holds '.' and '#'");
+
+    debug!("filled cols: {filled_cols:?}");
+    debug!("filled rows: {filled_rows:?}");
+    trace!("expanded:\n{expanded_map}");
+
+    let mut stars: HashSet<UVec2> = HashSet::new();
+
+    expanded_map.iter().for_each(|((x, y), space)| {
+        if let Space::Galaxy = space {
+            stars.insert(UVec2 {
+                x: x as u32,
+                y: y as u32,
+            });
+        }
+    });
+
+    debug!("{} stars", stars.len());
+
+    let ans = stars
+        .iter()
+        .map(|s1| {
+            stars
+                .iter()
+                .map(move |s2| {
+                    let d = s1.clone().as_ivec2() - s2.as_ivec2();
+
+                    let abs = d.abs();
+                    abs.x + abs.y
+                })
+                .sum::<i32>()
+        })
+        .sum::<i32>();
+
+    u32::try_from(ans).unwrap() / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn it_works() {
+        let result = process(
+            &parse(
+                "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 374);
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::{BTreeSet, HashSet};
+
+use glam::U64Vec2;
+use log::trace;
+
+use aoc_core::Grid;
+
+use crate::Space;
+
+pub fn process(starmap: &Grid<Space>) -> u64 {
+    sum_of_distances(starmap, 1000000)
+}
+
+fn sum_of_distances(starmap: &Grid<Space>, expansion: u64) -> u64 {
+    trace!("starmap:\n{starmap}");
+
+    let mut filled_cols: BTreeSet<u64> = BTreeSet::new();
+    let mut filled_rows: BTreeSet<u64> = BTreeSet::new();
+
+    let mut stars = HashSet::new();
+
+    starmap.iter().for_each(|((x, y), space)| {
+        if let Space::Galaxy = space {
+            filled_cols.insert(x as u64);
+            filled_rows.insert(y as u64);
+            stars.insert(U64Vec2::new(x as u64, y as u64));
+        }
+    });
+    let ans = stars
+        .iter()
+        .flat_map(|s1| {
+            stars.iter().map(move |s2| {
+                let min_v = U64Vec2::new(s1.x.min(s2.x), s1.y.min(s2.y));
+                let max_v = U64Vec2::new(s1.x.max(s2.x), s1.y.max(s2.y));
+                (min_v, max_v)
+            })
+        })
+        .map(|(min_v, max_v)| {
+            let v_stars_between = filled_rows.range(min_v.y..max_v.y).count() as u64;
+            let v_dist = max_v.y - min_v.y;
+            let v_spaces = v_dist - v_stars_between;
+
+            let h_stars_between = filled_cols.range(min_v.x..max_v.x).count() as u64;
+            let h_dist = max_v.x - min_v.x;
+            let h_spaces = h_dist - h_stars_between;
+
+            let n = expansion;
+
+            h_dist + (n - 1) * h_spaces + v_dist + (n - 1) * v_spaces
+        })
+        .sum::<u64>();
+
+    ans / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use rstest::rstest;
+
+    const EXAMPLE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn it_works() {
+        let result = sum_of_distances(&parse(EXAMPLE).unwrap(), 2);
+        assert_eq!(result, 374);
+    }
+    #[rstest]
+    #[case(10, 1030)]
+    #[case(100, 8410)]
+    fn larger_expansions(#[case] expansion: u64, #[case] expected: u64) {
+        let result = sum_of_distances(&parse(EXAMPLE).unwrap(), expansion);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,81 @@
+use rand::Rng;
+
+/// `size` rows of up to 20 springs, each drawn from a real arrangement of its groups
+/// before some springs are hidden, so every row has at least one arrangement.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let groups = (0..rng.gen_range(1..=5))
+                .map(|_| rng.gen_range(1..=4))
+                .collect::<Vec<usize>>();
+            row(rng, &groups, 0.5) + "\n"
+        })
+        .collect()
+}
+
+/// A row with `groups` of damaged springs laid out somewhere, each spring then hidden
+/// behind a `?` with probability `hide`.
+fn row(rng: &mut impl Rng, groups: &[usize], hide: f64) -> String {
+    let mut springs = ".".repeat(rng.gen_range(0..=2));
+    for (i, &group) in groups.iter().enumerate() {
+        if i > 0 {
+            springs.push_str(&".".repeat(rng.gen_range(1..=3)));
+        }
+        springs.push_str(&"#".repeat(group));
+    }
+    springs.push_str(&".".repeat(rng.gen_range(0..=2)));
+
+    let hidden = springs
+        .chars()
+        .map(|spring| if rng.gen_bool(hide) { '?' } else { spring })
+        .collect::<String>();
+    let groups = groups.iter().map(|g| g.to_string()).collect::<Vec<_>>();
+    format!("{hidden} {}", groups.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Ways to choose `k` things from `n`.
+    fn choose(n: u64, k: u64) -> u64 {
+        (0..k).fold(1, |ways, i| ways * (n - i) / (i + 1))
+    }
+
+    #[test]
+    fn every_row_has_an_arrangement() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            for record in parse(&input).unwrap() {
+                assert!(part1::process(std::slice::from_ref(&record)) >= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn counts_the_arrangements_of_hidden_rows() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..50 {
+            let groups = (0..rng.gen_range(1..=5))
+                .map(|_| rng.gen_range(1..=4))
+                .collect::<Vec<usize>>();
+            let (damaged, count) = (groups.iter().sum::<usize>(), groups.len());
+
+            // with every spring in view there is only the one arrangement, even unfolded
+            let shown = parse(&row(&mut rng, &groups, 0.0)).unwrap();
+            assert_eq!(part1::process(&shown), 1);
+            assert_eq!(part2::process(&shown), 1);
+
+            // with none in view, the working springs left over after the gap between each
+            // pair of groups can go before, between or after any of them
+            let hidden = parse(&row(&mut rng, &groups, 1.0)).unwrap();
+            let spare = hidden[0].field.len() - damaged - (count - 1);
+            assert_eq!(
+                part1::process(&hidden),
+                choose((spare + count) as u64, count as u64)
+            );
+        }
+    }
+}
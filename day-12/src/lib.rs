@@ -0,0 +1,133 @@
+use std::iter;
+
+use aoc_core::{
+    parsers::{expect, integer, lines, tag, IResult},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{
+    character::complete::anychar,
+    combinator::{cut, map, map_opt, verify},
+    multi::{many1, separated_list1},
+    sequence::{pair, terminated},
+};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day12;
+
+#[derive(Debug, Clone)]
+pub enum Field {
+    Op,
+    Dmg,
+    Ukn,
+}
+
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(
+            (self, other),
+            (_, Field::Ukn) | (Field::Ukn, _) | (Field::Op, Field::Op) | (Field::Dmg, Field::Dmg)
+        )
+    }
+}
+
+impl TryFrom<char> for Field {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Field::Op),
+            '.' => Ok(Field::Dmg),
+            '?' => Ok(Field::Ukn),
+            _ => Err(()),
+        }
+    }
+}
+/// One row of springs and the sizes of its damaged groups.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub field: Vec<Field>,
+    pub groups: Vec<u64>,
+}
+
+impl Record {
+    /// Repeats the record `mult` times, joining the copies of the field with unknowns.
+    pub fn unfold(&self, mult: usize) -> Record {
+        let field = iter::repeat_n(
+            self.field.iter().cloned().chain(iter::once(Field::Ukn)),
+            mult - 1,
+        )
+        .flatten()
+        .chain(self.field.iter().cloned())
+        .collect::<Vec<_>>();
+
+        let groups = iter::repeat_n(self.groups.iter().copied(), mult)
+            .flatten()
+            .collect::<Vec<_>>();
+
+        Record { field, groups }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+    Source::new(Day12::DAY, input).run(lines(parse_line), input)
+}
+
+// ???.### 1,1,3
+fn parse_line(input: &str) -> IResult<'_, Record> {
+    let spring = || {
+        expect(
+            "'#', '.' or '?'",
+            map_opt(anychar, |c| Field::try_from(c).ok()),
+        )
+    };
+    let field = terminated(many1(spring()), expect("'#', '.' or '?'", tag(" ")));
+    let size = verify(integer, |&size: &u64| size > 0);
+    let groups = separated_list1(tag(","), cut(expect("a group size", size)));
+    map(pair(field, groups), |(field, groups)| Record {
+        field,
+        groups,
+    })(input)
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Vec<Record>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day12 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_bad_group() {
+        let err = parse("???.### 1,1,3\n.??..??...?##. 1,,3").unwrap_err();
+        assert_eq!(err, ParseError::new(12, 2, 18, "a group size"));
+        let err = parse("???.### 1,0,3").unwrap_err();
+        assert_eq!(err, ParseError::new(12, 1, 11, "a group size"));
+    }
+}
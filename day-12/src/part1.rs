@@ -0,0 +1,154 @@
+use std::{collections::HashMap, iter};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{Field, Record};
+
+/** (wiggle, #group) */
+type PermCache = HashMap<(usize, u64, u64), u64>;
+
+fn _field_perms(
+    row_sl: &[Field],
+    groups: &[u64],
+    wiggle: u64,
+    cache: &mut PermCache,
+    th: bool,
+) -> u64 {
+    use Field::*;
+
+    let buffer = if groups.len() == 1 { 0 } else { 1 };
+
+    if groups.is_empty() {
+        return match row_sl.iter().zip(iter::repeat(Dmg)).all(|(a, b)| a.eq(&b)) {
+            true => 1,
+            false => 0,
+        };
+    };
+
+    let curr_group = groups[0];
+
+    let x = (0..=wiggle)
+        .map(|n| {
+            // check cache
+            let cache_key = (groups.len() - 1, wiggle - n, n);
+            if th {
+                if let Some(v) = cache.get(&cache_key) {
+                    return *v;
+                }
+            }
+
+            let partial = row_sl
+                .iter()
+                .zip(
+                    std::iter::repeat_n(&Dmg, (n) as usize)
+                        .chain(std::iter::repeat_n(&Op, curr_group as usize))
+                        .chain(std::iter::repeat_n(&Dmg, buffer)),
+                )
+                .all(|(a, b)| a.eq(b));
+
+            match partial {
+                false => {
+                    cache.insert(cache_key, 0);
+                    0
+                }
+                true => {
+                    let sect_len = (n + curr_group) as usize + buffer;
+                    let perms =
+                        _field_perms(&row_sl[sect_len..], &groups[1..], wiggle - n, cache, th);
+                    cache.insert(cache_key, perms);
+                    perms
+                }
+            }
+        })
+        .sum::<_>();
+
+    x
+}
+
+/// How much room the groups have to move about in, or `None` if they don't fit at all.
+fn calculate_wiggle(field: &[Field], groups: &[u64]) -> Option<u64> {
+    let field_len = field.len() as u64;
+
+    let min_len = groups.iter().map(|x| x + 1).sum::<u64>() - 1;
+    field_len.checked_sub(min_len)
+}
+
+pub fn process(records: &[Record]) -> u64 {
+    count_arrangements(records, 1)
+}
+
+pub(crate) fn count_arrangements(records: &[Record], mult: usize) -> u64 {
+    // every line is counted on its own, so with the `parallel` feature they share out across threads
+    #[cfg(feature = "parallel")]
+    let records = records.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let records = records.iter();
+
+    records
+        .map(|record| record.unfold(mult))
+        .map(|Record { field, groups }| {
+            let cache = &mut PermCache::new();
+            calculate_wiggle(&field, &groups).map_or(0, |wiggle| {
+                _field_perms(&field, &groups, wiggle, cache, true)
+            })
+        })
+        .sum::<u64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use rstest::rstest;
+
+    #[test]
+    fn it_works() {
+        let result = process(
+            &parse(
+                "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn counts_no_arrangements_for_groups_too_long() {
+        assert_eq!(process(&parse("??? 1,1,1,1").unwrap()), 0);
+    }
+    #[rstest]
+    #[case("???.### 1,1,3", 1)]
+    #[case(".??..??...?##. 1,1,3", 16384)]
+    #[case("?#?#?#?#?#?#?#? 1,3,1,6", 1)]
+    #[case("????.#...#... 4,1,1", 16)]
+    #[case("????.######..#####. 1,6,5", 2500)]
+    #[case("?###???????? 3,2,1", 506250)]
+    fn resting(#[case] input: &str, #[case] expected: u64) {
+        let cache = &mut PermCache::new();
+        let Record { field, groups } = parse(input).unwrap()[0].unfold(5);
+        let wiggle = calculate_wiggle(&field, &groups).unwrap();
+        let perms = _field_perms(&field, &groups, wiggle, cache, true);
+        assert_eq!(perms, expected);
+    }
+    #[rstest]
+    fn it_works_2() {
+        use Field::*;
+        assert!(Ukn.eq(&Ukn));
+        assert!(Ukn.eq(&Op));
+        assert!(Ukn.eq(&Dmg));
+
+        assert!(Op.eq(&Ukn));
+        assert!(Op.eq(&Op));
+        assert!(!Op.eq(&Dmg));
+
+        assert!(Op.eq(&Ukn));
+        assert!(Op.eq(&Op));
+        assert!(!Op.eq(&Dmg));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{part1::count_arrangements, Record};
+
+pub fn process(records: &[Record]) -> u64 {
+    count_arrangements(records, 5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn it_works() {
+        let result = process(
+            &parse(
+                "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, 525152);
+    }
+}
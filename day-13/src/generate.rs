@@ -0,0 +1,99 @@
+use rand::Rng;
+
+type Pattern = Vec<Vec<bool>>;
+
+/// `size` patterns of rocks and ash, each with exactly one perfect reflection and exactly
+/// one more that a single smudge would make perfect.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let patterns = (0..size)
+        .map(|_| loop {
+            let pattern = candidate(rng);
+            if reflections(&pattern, 0) == 1 && reflections(&pattern, 1) == 1 {
+                break pattern;
+            }
+        })
+        .map(|pattern| {
+            pattern
+                .iter()
+                .map(|row| row.iter().map(|&rock| if rock { '#' } else { '.' }))
+                .map(|row| row.collect::<String>() + "\n")
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    patterns.join("\n")
+}
+
+/// A pattern reflected perfectly between two rows near the top, and left to right with one
+/// smudge in a row the first reflection doesn't reach; turned on its side half the time.
+fn candidate<R: Rng>(rng: &mut R) -> Pattern {
+    let (width, height) = (rng.gen_range(5..=15), rng.gen_range(7..=15));
+    let across = rng.gen_range(1..width);
+    let down = rng.gen_range(1..=(height - 1) / 2);
+
+    let mirrored = |rng: &mut R| {
+        let mut row = (0..width).map(|_| rng.gen()).collect::<Vec<bool>>();
+        for x in across..width.min(2 * across) {
+            row[x] = row[2 * across - 1 - x];
+        }
+        row
+    };
+    let mut pattern = (0..down).map(|_| mirrored(rng)).collect::<Vec<_>>();
+    pattern.extend(pattern.clone().into_iter().rev());
+    pattern.extend((2 * down..height).map(|_| mirrored(rng)));
+
+    let x = rng.gen_range(across.saturating_sub(width - across)..across);
+    let y = rng.gen_range(2 * down..height);
+    pattern[y][x] = !pattern[y][x];
+
+    if rng.gen() {
+        transpose(&pattern)
+    } else {
+        pattern
+    }
+}
+
+fn transpose(pattern: &Pattern) -> Pattern {
+    (0..pattern[0].len())
+        .map(|x| pattern.iter().map(|row| row[x]).collect())
+        .collect()
+}
+
+/// How many lines, between rows or between columns, reflect with exactly `smudges` tiles
+/// out of place.
+fn reflections(pattern: &Pattern, smudges: usize) -> usize {
+    let between_rows = |pattern: &Pattern| {
+        (1..pattern.len())
+            .filter(|&line| {
+                let above = pattern[..line].iter().rev();
+                let wrong = above
+                    .zip(&pattern[line..])
+                    .flat_map(|(a, b)| a.iter().zip(b).filter(|(a, b)| a != b))
+                    .count();
+                wrong == smudges
+            })
+            .count()
+    };
+    between_rows(pattern) + between_rows(&transpose(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn every_pattern_has_a_reflection_and_a_smudge() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+            let areas = parse(&input).unwrap();
+            assert_eq!(areas.len(), 20);
+            // the smudged reflection can't be on the same line as the perfect one
+            for area in areas.chunks(1) {
+                let (clean, smudged) =
+                    (part1::process(area).unwrap(), part2::process(area).unwrap());
+                assert!(clean > 0 && smudged > 0 && clean != smudged);
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use aoc_core::{
+    parsers::{blocks, grid},
+    Generate, Grid, ParseError, Solution, Source, Tile, Unsolvable,
+};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+pub struct Day13;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Terrain {
+    Ash,
+    Rock,
+}
+
+impl Tile for Terrain {
+    const EXPECTED: &'static str = "'.' or '#'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Terrain::Ash),
+            '#' => Some(Terrain::Rock),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Terrain::Ash => '.',
+            Terrain::Rock => '#',
+        }
+    }
+}
+
+pub type Area = Grid<Terrain>;
+
+/// Splits the input into its patterns of ash (`.`) and rocks (`#`).
+pub fn parse(input: &str) -> Result<Vec<Area>, ParseError> {
+    Source::new(Day13::DAY, input).run(blocks(grid), input)
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Area>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        part2::process(input)
+    }
+}
+
+impl Generate for Day13 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_unknown_tile() {
+        let err = parse("#.#\n.#.\n\n##.\n.o#").unwrap_err();
+        assert_eq!(err, ParseError::new(13, 5, 2, "'.' or '#'"));
+    }
+}
@@ -0,0 +1,127 @@
+use aoc_core::{Solution, Unsolvable};
+
+use crate::{Area, Day13};
+
+fn find_area_vertical_symmetry(area: &Area) -> Option<usize> {
+    find_area_horizontal_symmetry(&area.transpose())
+}
+
+fn find_area_horizontal_symmetry(area: &Area) -> Option<usize> {
+    let rows = area.rows().collect::<Vec<_>>();
+
+    (1..rows.len()).find(|&n| rows[..n].iter().rev().zip(&rows[n..]).all(|(a, b)| a.eq(b)))
+}
+
+pub fn process(areas: &[Area]) -> Result<u32, Unsolvable> {
+    areas
+        .iter()
+        .enumerate()
+        .map(|(i, area)| {
+            if let Some(ans) = find_area_horizontal_symmetry(area) {
+                return Ok(ans * 100);
+            }
+            if let Some(ans) = find_area_vertical_symmetry(area) {
+                return Ok(ans);
+            }
+            let reason = format!("pattern {} has no line of reflection", i + 1);
+            Err(Unsolvable::new(Day13::DAY, reason))
+        })
+        .sum::<Result<usize, _>>()?
+        .try_into()
+        .map_err(|_| Unsolvable::new(Day13::DAY, "the summary doesn't fit in a u32"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn area(input: &str) -> Area {
+        parse(input).unwrap().remove(0)
+    }
+
+    #[test]
+    fn it_works() {
+        let result = process(
+            &parse(
+                "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, Ok(405));
+    }
+
+    #[test]
+    fn reports_pattern_without_reflection() {
+        let areas = parse("#.\n#.\n\n#..\n.#.\n..#").unwrap();
+        let err = process(&areas).unwrap_err();
+        assert_eq!(err.reason, "pattern 2 has no line of reflection");
+    }
+    #[test]
+    fn it_works_vert() {
+        let result = find_area_vertical_symmetry(&area(
+            "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.",
+        ));
+        assert_eq!(result, Some(5));
+    }
+    #[test]
+    fn it_works_no_dect_vert() {
+        let result = find_area_vertical_symmetry(&area(
+            "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#",
+        ));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn it_works_hori() {
+        let result = find_area_horizontal_symmetry(&area(
+            "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#",
+        ));
+        assert_eq!(result, Some(4));
+    }
+    #[test]
+    fn it_works_no_dect_hori() {
+        let result = find_area_horizontal_symmetry(&area(
+            "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.",
+        ));
+        assert_eq!(result, None);
+    }
+}
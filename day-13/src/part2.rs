@@ -0,0 +1,199 @@
+use std::iter;
+
+use aoc_core::{Solution, Unsolvable};
+
+use crate::{Area, Day13, Terrain};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Symmetry {
+    Perfect = 0,
+    Imperfect = 1,
+    Asymmetric = 2,
+}
+
+impl std::ops::Add for Symmetry {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        match self as u8 + rhs as u8 {
+            0 => Self::Perfect,
+            1 => Self::Imperfect,
+            _ => Self::Asymmetric,
+        }
+    }
+}
+
+impl iter::Sum for Symmetry {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::Perfect, |acc, x| acc + x)
+    }
+}
+
+fn is_match(a: &Terrain, b: &Terrain) -> Symmetry {
+    match a.eq(b) {
+        true => Symmetry::Perfect,
+        false => Symmetry::Imperfect,
+    }
+}
+
+fn find_area_partial_vertical_symmetry(area: &Area) -> Vec<(usize, Symmetry)> {
+    find_area_partial_horizontal_symmetry(&area.transpose())
+}
+
+fn find_area_partial_horizontal_symmetry(area: &Area) -> Vec<(usize, Symmetry)> {
+    let rows = area.rows().collect::<Vec<_>>();
+
+    (1..rows.len())
+        .filter_map(|n| {
+            let x = rows[..n]
+                .iter()
+                .rev()
+                .zip(&rows[n..])
+                .map(|(a, b)| {
+                    a.iter()
+                        .zip(b.iter())
+                        .map(|(a, b)| is_match(a, b))
+                        .sum::<Symmetry>()
+                })
+                .sum::<Symmetry>();
+
+            match x {
+                Symmetry::Asymmetric => None,
+                r => Some((n, r)),
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+pub fn process(areas: &[Area]) -> Result<u32, Unsolvable> {
+    areas
+        .iter()
+        .enumerate()
+        .map(|(i, area)| {
+            find_area_partial_horizontal_symmetry(area)
+                .iter()
+                .filter_map(|(n, sym)| match *sym {
+                    Symmetry::Imperfect => Some(*n * 100),
+                    _ => None,
+                })
+                .chain(
+                    find_area_partial_vertical_symmetry(area)
+                        .iter()
+                        .filter_map(|(n, sym)| match *sym {
+                            Symmetry::Imperfect => Some(*n),
+                            _ => None,
+                        }),
+                )
+                .next()
+                .ok_or_else(|| {
+                    let reason = format!("pattern {} has no smudged line of reflection", i + 1);
+                    Unsolvable::new(Day13::DAY, reason)
+                })
+        })
+        .sum::<Result<usize, _>>()?
+        .try_into()
+        .map_err(|_| Unsolvable::new(Day13::DAY, "the summary doesn't fit in a u32"))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::parse;
+
+    fn area(input: &str) -> Area {
+        parse(input).unwrap().remove(0)
+    }
+
+    #[test]
+    fn it_works_2() {
+        let result = process(
+            &parse(
+                "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#",
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, Ok(400));
+    }
+
+    #[test]
+    fn reports_pattern_without_smudged_reflection() {
+        // every line of reflection needs at least two tiles fixed
+        let areas = parse("#..\n.#.\n..#").unwrap();
+        let err = process(&areas).unwrap_err();
+        assert_eq!(err.reason, "pattern 1 has no smudged line of reflection");
+    }
+    #[test]
+    fn it_works_vert_partial() {
+        // I modified example to get this to work
+        let compvec = vec![(5_usize, Symmetry::Imperfect)];
+        let result = find_area_partial_vertical_symmetry(&area(
+            "#.##..##.
+..#.#..#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.",
+        ));
+        assert_eq!(result, compvec);
+    }
+    #[test]
+    fn it_works_hori_partial_1() {
+        let compvec = vec![(3_usize, Symmetry::Imperfect)];
+        let result = find_area_partial_horizontal_symmetry(&area(
+            "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.",
+        ));
+        assert_eq!(result, compvec);
+    }
+
+    #[test]
+    fn it_works_hori_partial_2() {
+        let compvec = vec![(1_usize, Symmetry::Imperfect), (4_usize, Symmetry::Perfect)];
+        let result = find_area_partial_horizontal_symmetry(&area(
+            "#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#",
+        ));
+        assert_eq!(result, compvec);
+    }
+    #[test]
+    fn adding_syms() {
+        use Symmetry::*;
+        assert_eq!(Perfect + Perfect, Perfect);
+        assert_eq!(Perfect + Imperfect, Imperfect);
+        assert_eq!(Perfect + Asymmetric, Asymmetric);
+
+        assert_eq!(Imperfect + Perfect, Imperfect);
+        assert_eq!(Imperfect + Imperfect, Asymmetric);
+        assert_eq!(Imperfect + Asymmetric, Asymmetric);
+
+        assert_eq!(Asymmetric + Perfect, Asymmetric);
+        assert_eq!(Asymmetric + Imperfect, Asymmetric);
+        assert_eq!(Asymmetric + Asymmetric, Asymmetric);
+    }
+}
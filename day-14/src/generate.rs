@@ -0,0 +1,45 @@
+use rand::Rng;
+
+/// A `size` by `size` platform with about a quarter of it round rocks and a sixth cube rocks.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(match rng.gen_range(0..12) {
+                0..=2 => 'O',
+                3..=4 => '#',
+                _ => '.',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part1::calculate_load, part2, tilt, Board, Space};
+    use aoc_core::Direction;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn tilting_north_only_raises_the_load() {
+        let rounds = |board: &Board| {
+            board
+                .iter()
+                .filter(|(_, space)| **space == Space::Round)
+                .count()
+        };
+        for seed in 0..5 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 30);
+            let board = parse(&input).unwrap();
+            let tilted = tilt(&board, Direction::North);
+            assert_eq!(rounds(&tilted), rounds(&board));
+            assert_eq!(part1::process(&board), calculate_load(&tilted));
+            assert!(calculate_load(&tilted) >= calculate_load(&board));
+            // a round rock adds at most the full height of the board
+            assert!(part2::process(&board) <= 30 * rounds(&board) as u64);
+        }
+    }
+}
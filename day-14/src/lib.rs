@@ -0,0 +1,186 @@
+use aoc_core::{
+    Animate, Direction, Generate, Grid, Image, ParseError, Solution, Source, Tile, Unsolvable,
+};
+use rand::Rng;
+
+pub mod generate;
+pub mod part1;
+pub mod part2;
+pub mod render;
+pub mod step;
+
+pub struct Day14;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Space {
+    Empty,
+    Block,
+    Round,
+}
+
+impl Tile for Space {
+    const EXPECTED: &'static str = "'.', '#' or 'O'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Space::Empty),
+            '#' => Some(Space::Block),
+            'O' => Some(Space::Round),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Space::Empty => '.',
+            Space::Block => '#',
+            Space::Round => 'O',
+        }
+    }
+}
+
+pub type Board = Grid<Space>;
+
+/// Rolls every round rock as far left as it will go.
+pub fn tilt_left(board: &Board) -> Board {
+    let rows = board.rows().map(|line| {
+        let mut start = 0;
+        let mut new_line: Vec<Space> = Vec::with_capacity(line.len());
+        while start < line.len() {
+            let (rounds, empties): (Vec<u32>, Vec<u32>) = line[start..]
+                .iter()
+                .take_while(|x| **x != Space::Block)
+                .map(|x| match x {
+                    Space::Empty => (0, 1),
+                    Space::Round => (1, 0),
+                    Space::Block => panic!("Should not happen"),
+                })
+                .unzip::<_, _, _, _>();
+            let num_rounds = rounds.iter().sum::<u32>();
+            let num_empties = empties.iter().sum::<u32>();
+            (0..num_rounds).for_each(|_| new_line.push(Space::Round));
+            (0..num_empties).for_each(|_| new_line.push(Space::Empty));
+            start += num_rounds as usize + num_empties as usize + 1;
+            if start > line.len() {
+                break;
+            }
+            new_line.push(Space::Block);
+        }
+        new_line
+    });
+    Grid::from_rows(rows).expect("tilting keeps every row the same length")
+}
+
+/// The tilts of one spin cycle, in order.
+pub const SPIN: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+/// Rolls every round rock as far towards `direction` as it will go.
+pub fn tilt(board: &Board, direction: Direction) -> Board {
+    match direction {
+        Direction::North => tilt_left(&board.rotate_left()).rotate_right(),
+        Direction::East => tilt_left(&board.rotate_left().rotate_left())
+            .rotate_right()
+            .rotate_right(),
+        Direction::South => tilt_left(&board.rotate_right()).rotate_left(),
+        Direction::West => tilt_left(board),
+    }
+}
+
+pub fn parse(input: &str) -> Result<Board, ParseError> {
+    Grid::parse(&Source::new(Day14::DAY, input), input)
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = Board;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day14 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+impl Animate for Day14 {
+    fn frames(input: &Self::Input<'_>) -> Vec<Image> {
+        render::frames(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const ORIGINAL_BOARD: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    const MOVED_BOARD: &str = "OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+
+    #[test]
+    fn can_move_board() {
+        let board = parse(ORIGINAL_BOARD).unwrap();
+        let rot_left = board.rotate_left();
+        let tilted = tilt_left(&rot_left);
+        let rot_right = tilted.rotate_right();
+
+        let moved = parse(MOVED_BOARD).unwrap();
+        assert_eq!(rot_right, moved);
+    }
+
+    #[test]
+    fn tilts_each_way() {
+        let board = parse("O.#\n..O\n.O.").unwrap();
+        assert_eq!(
+            tilt(&board, Direction::North).to_string(),
+            "OO#\n..O\n...\n"
+        );
+        assert_eq!(tilt(&board, Direction::East).to_string(), ".O#\n..O\n..O\n");
+        assert_eq!(
+            tilt(&board, Direction::South).to_string(),
+            "..#\n...\nOOO\n"
+        );
+        assert_eq!(tilt(&board, Direction::West).to_string(), "O.#\nO..\nO..\n");
+    }
+
+    #[test]
+    fn reports_unknown_rock() {
+        let err = parse("O..#\n.@..").unwrap_err();
+        assert_eq!(err, ParseError::new(14, 2, 2, "'.', '#' or 'O'"));
+    }
+}
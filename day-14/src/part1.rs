@@ -0,0 +1,57 @@
+use crate::{tilt_left, Board, Space};
+
+/// Load on the north support beams.
+pub fn calculate_load(board: &Board) -> u64 {
+    let height = board.height();
+
+    board
+        .rows()
+        .enumerate()
+        .map(|(i, line)| {
+            let x = line
+                .iter()
+                .filter(|space| matches!(space, Space::Round))
+                .count()
+                * (height - i);
+            u64::try_from(x).unwrap()
+        })
+        .sum::<u64>()
+}
+
+pub fn process(board: &Board) -> u64 {
+    let rot_left = board.rotate_left();
+    let tilted = tilt_left(&rot_left);
+    let rot_right = tilted.rotate_right();
+
+    calculate_load(&rot_right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const MOVED_BOARD: &str = "OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+
+    #[test]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/14/example.txt")).unwrap());
+        assert_eq!(result, 136);
+    }
+
+    #[test]
+    fn test_calculate_load() {
+        let result = parse(MOVED_BOARD).unwrap();
+        let load = calculate_load(&result);
+        assert_eq!(load, 136);
+    }
+}
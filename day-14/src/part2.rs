@@ -0,0 +1,69 @@
+use aoc_core::Orbit;
+use log::debug;
+
+use crate::{tilt_left, Board, Space};
+
+fn calculate_load(board: &Board) -> u64 {
+    board
+        .rows()
+        .flat_map(|line| {
+            line.iter()
+                .rev()
+                .enumerate()
+                .filter_map(|(i, space)| match space {
+                    Space::Round => Some(i as u64 + 1),
+                    _ => None,
+                })
+        })
+        .sum::<u64>()
+}
+
+pub fn process(board: &Board) -> u64 {
+    const CYCLE_COUNT: usize = 1000000000;
+
+    // kept turned a quarter left, so north is on the left where tilt_left rolls to
+    let orbit = Orbit::trace(board.rotate_left(), spin);
+    let cycle = orbit.cycle();
+    debug!(
+        "board at cycle {} repeats every {} cycles",
+        cycle.start, cycle.length
+    );
+
+    calculate_load(orbit.nth(CYCLE_COUNT))
+}
+
+/// One spin cycle, tilting north, west, south and east in turn.
+fn spin(board: &Board) -> Board {
+    (0..4).fold(board.clone(), |board, _| tilt_left(&board).rotate_right())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const MOVED_BOARD: &str = "OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+
+    #[test]
+    fn it_works() {
+        let result = process(&parse(include_str!("../../examples/14/example.txt")).unwrap());
+        assert_eq!(result, 64);
+    }
+
+    #[test]
+    fn test_calculate_load() {
+        let result = parse(MOVED_BOARD).unwrap().rotate_left();
+
+        let load = calculate_load(&result);
+        assert_eq!(load, 136);
+    }
+}
@@ -0,0 +1,41 @@
+use aoc_core::{Event, Image, Paint, Rgb, Step};
+
+use crate::{step::SpinCycle, Board, Space};
+
+impl Paint for Space {
+    fn paint(&self) -> Rgb {
+        match self {
+            Space::Empty => Rgb(30, 30, 46),
+            Space::Block => Rgb(108, 112, 134),
+            Space::Round => Rgb(250, 179, 135),
+        }
+    }
+}
+
+/// The board after every tilt of every spin cycle, until a cycle ends on a board seen before.
+pub fn frames(board: &Board) -> Vec<Image> {
+    let mut spin = SpinCycle::new(board);
+    let mut frames = vec![Image::new(board)];
+    loop {
+        let event = spin.step();
+        frames.push(Image::new(spin.board()));
+        if let Event::Breakpoint(_) = event {
+            return frames;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn stops_once_the_spin_repeats() {
+        let board = parse(include_str!("../../examples/14/example.txt")).unwrap();
+        let frames = frames(&board);
+        assert_eq!(frames[0], Image::new(&board));
+        // the example settles into a loop after 10 spin cycles
+        assert_eq!(frames.len(), 1 + 4 * 10);
+    }
+}
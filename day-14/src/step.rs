@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use colored::Colorize;
+
+use aoc_core::{Event, Step};
+
+use crate::{part1::calculate_load, tilt, Board, Space, SPIN};
+
+/// The dish partway through its spin cycles, one tilt per step.
+#[derive(Debug, Clone)]
+pub struct SpinCycle {
+    board: Board,
+    tilts: usize,
+    /// Spin cycle each board was first seen at the end of.
+    seen: HashMap<Board, usize>,
+    repeated: bool,
+}
+
+impl SpinCycle {
+    pub fn new(board: &Board) -> Self {
+        Self {
+            board: board.clone(),
+            tilts: 0,
+            seen: HashMap::from([(board.clone(), 0)]),
+            repeated: false,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+}
+
+impl Step for SpinCycle {
+    fn step(&mut self) -> Event {
+        self.board = tilt(&self.board, SPIN[self.tilts % SPIN.len()]);
+        self.tilts += 1;
+        if !self.tilts.is_multiple_of(SPIN.len()) {
+            return Event::Quiet;
+        }
+
+        let cycle = self.tilts / SPIN.len();
+        match self.seen.get(&self.board) {
+            Some(&first) if !self.repeated => {
+                self.repeated = true;
+                Event::Breakpoint(format!(
+                    "cycle {cycle} ends on the same board as cycle {first}"
+                ))
+            }
+            Some(_) => Event::Quiet,
+            None => {
+                self.seen.insert(self.board.clone(), cycle);
+                Event::Quiet
+            }
+        }
+    }
+
+    fn view(&self) -> String {
+        let mut out = format!(
+            "cycle {}, next tilt {:?}, load {}\n",
+            self.tilts / SPIN.len(),
+            SPIN[self.tilts % SPIN.len()],
+            calculate_load(&self.board)
+        );
+        for row in self.board.rows() {
+            for space in row {
+                let tile = match space {
+                    Space::Empty => ".".dimmed(),
+                    Space::Block => "#".bright_black(),
+                    Space::Round => "O".yellow().bold(),
+                };
+                out.push_str(&tile.to_string());
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn breaks_when_a_cycle_repeats() {
+        let board = parse(include_str!("../../examples/14/example.txt")).unwrap();
+        let mut spin = SpinCycle::new(&board);
+        let tilts = (1..)
+            .find(|_| matches!(spin.step(), Event::Breakpoint(_)))
+            .unwrap();
+        assert_eq!(tilts, 4 * 10);
+    }
+}
@@ -0,0 +1,51 @@
+use rand::{seq::SliceRandom, Rng};
+
+/// `size` steps over about a third as many labels, so lenses get replaced and removed.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let labels = (0..size / 3 + 1)
+        .map(|_| {
+            (0..rng.gen_range(2..=6))
+                .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    let steps = (0..size)
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            match rng.gen_ratio(2, 3) {
+                true => format!("{label}={}", rng.gen_range(1..=9)),
+                false => format!("{label}-"),
+            }
+        })
+        .collect::<Vec<_>>();
+    steps.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn removing_every_label_empties_the_boxes() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 100);
+            let steps = parse(&input).unwrap();
+            assert_eq!(steps.len(), 100);
+            assert!(part1::process(&steps) <= 255 * 100);
+
+            let removals = steps
+                .iter()
+                .map(|step| {
+                    step.text
+                        .trim_end_matches(|c: char| !c.is_ascii_lowercase())
+                })
+                .map(|label| format!(",{label}-"))
+                .collect::<String>();
+            let cleared = format!("{}{removals}\n", input.trim_end());
+            assert_eq!(part2::process(&parse(&cleared).unwrap()), 0);
+        }
+    }
+}
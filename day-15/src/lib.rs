@@ -1,2 +1,138 @@
+use aoc_core::{
+    parsers::{expect, integer, tag, IResult},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{
+    branch::alt,
+    character::complete::alpha1,
+    combinator::{consumed, cut, map},
+    multi::separated_list1,
+    sequence::{pair, preceded},
+};
+use rand::Rng;
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+pub struct Day15;
+
+#[derive(Debug, PartialEq)]
+pub enum Operation<'a> {
+    Plus(&'a str, u64),
+    Minus(&'a str),
+}
+
+/// One comma-separated step of the initialization sequence.
+#[derive(Debug, PartialEq)]
+pub struct Step<'a> {
+    /// The step exactly as written, which is what part 1 hashes.
+    pub text: &'a str,
+    pub operation: Operation<'a>,
+}
+
+pub fn get_hash(input: &str) -> u64 {
+    input.chars().fold(0, |acc, c| {
+        let mut new = acc + c as u64;
+        new *= 17;
+        new %= 256;
+        new
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    let steps = separated_list1(tag(","), cut(parse_step));
+    Source::new(Day15::DAY, input).run(steps, input)
+}
+
+// rn=1 or cm-
+fn parse_step(input: &str) -> IResult<'_, Step<'_>> {
+    let focal_length = preceded(tag("="), cut(expect("a focal length", integer)));
+    let operation = expect(
+        "'=' or '-'",
+        alt((map(focal_length, Some), map(tag("-"), |_| None))),
+    );
+    map(
+        consumed(pair(alpha1, operation)),
+        |(text, (label, focal_length))| Step {
+            text,
+            operation: match focal_length {
+                Some(focal_length) => Operation::Plus(label, focal_length),
+                None => Operation::Minus(label),
+            },
+        },
+    )(input)
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<Step<'a>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day15 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("HASH", 52)]
+    #[case("rn=1", 30)]
+    #[case("cm-", 253)]
+    #[case("qp=3", 97)]
+    #[case("cm=2", 47)]
+    #[case("qp-", 14)]
+    #[case("pc=4", 180)]
+    #[case("ot=9", 9)]
+    #[case("ab=5", 197)]
+    #[case("pc-", 48)]
+    #[case("pc=6", 214)]
+    #[case("ot=7", 231)]
+    fn test_hash(#[case] input: &str, #[case] expected: u64) {
+        let result = get_hash(input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("rn=1", Operation::Plus("rn", 1))]
+    #[case("qp=3", Operation::Plus("qp", 3))]
+    #[case("cm=2", Operation::Plus("cm", 2))]
+    #[case("pc=4", Operation::Plus("pc", 4))]
+    #[case("ot=9", Operation::Plus("ot", 9))]
+    #[case("ab=5", Operation::Plus("ab", 5))]
+    #[case("ot=7", Operation::Plus("ot", 7))]
+    #[case("pc=6", Operation::Plus("pc", 6))]
+    #[case("cm-", Operation::Minus("cm"))]
+    #[case("pc-", Operation::Minus("pc"))]
+    #[case("qp-", Operation::Minus("qp"))]
+    fn test_hash_2(#[case] input: &'static str, #[case] expected: Operation) {
+        let result = parse(input).unwrap().remove(0).operation;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn reports_missing_operation() {
+        let err = parse("rn=1,cm-,qp").unwrap_err();
+        assert_eq!(err, ParseError::new(15, 1, 12, "'=' or '-'"));
+    }
+}
@@ -0,0 +1,38 @@
+use rand::Rng;
+
+/// A `size` by `size` contraption with a mirror or splitter in about one tile in eight.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(match rng.gen_range(0..32) {
+                0 => '/',
+                1 => '\\',
+                2 => '|',
+                3 => '-',
+                _ => '.',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn best_start_beats_the_corner() {
+        for seed in 0..5 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 30);
+            let contraption = parse(&input).unwrap();
+            let (corner, best) = (part1::process(&contraption), part2::process(&contraption));
+            // entering at the top left heading east is one of the starts part two tries
+            assert!((1..=best).contains(&corner));
+            assert!(best <= 30 * 30);
+        }
+    }
+}
@@ -1,2 +1,169 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    Animate, Direction, Generate, Grid, Image, ParseError, Solution, Source, Tile, Unsolvable,
+};
+use rand::Rng;
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+pub mod render;
+pub mod step;
+
+pub struct Day16;
+
+#[derive(Debug)]
+pub enum Spot {
+    Empty,         // .
+    BackMirror,    // \
+    ForwardMirror, // /
+    SplitVert,     // |
+    SplitHori,     // -
+}
+
+impl Tile for Spot {
+    const EXPECTED: &'static str = "'.', a mirror or a splitter";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Spot::Empty),
+            '\\' => Some(Spot::BackMirror),
+            '/' => Some(Spot::ForwardMirror),
+            '|' => Some(Spot::SplitVert),
+            '-' => Some(Spot::SplitHori),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Spot::Empty => '.',
+            Spot::BackMirror => '\\',
+            Spot::ForwardMirror => '/',
+            Spot::SplitVert => '|',
+            Spot::SplitHori => '-',
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Contraption {
+    pub value: Grid<Spot>,
+}
+
+pub type Position = (i64, i64);
+
+impl Contraption {
+    pub fn get(&self, position: Position) -> Option<&Spot> {
+        let x = usize::try_from(position.0).ok()?;
+        let y = usize::try_from(position.1).ok()?;
+        self.value.get(x, y)
+    }
+}
+
+impl Display for Contraption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Beam {
+    pub position: Position,
+    pub direction: Direction,
+}
+
+impl Beam {
+    pub fn step(&mut self) {
+        self.position = self.direction.step(self.position);
+    }
+
+    pub fn evaluate(&mut self, spot: &Spot) -> Option<Beam> {
+        match spot {
+            Spot::Empty => {}
+            Spot::BackMirror => match self.direction {
+                Direction::North => self.direction = Direction::West,
+                Direction::West => self.direction = Direction::North,
+                Direction::South => self.direction = Direction::East,
+                Direction::East => self.direction = Direction::South,
+            },
+            Spot::ForwardMirror => match self.direction {
+                Direction::North => self.direction = Direction::East,
+                Direction::East => self.direction = Direction::North,
+                Direction::South => self.direction = Direction::West,
+                Direction::West => self.direction = Direction::South,
+            },
+            Spot::SplitVert => match self.direction {
+                Direction::West | Direction::East => {
+                    let mut new_beam = *self;
+                    self.direction = Direction::South; // << ---
+                    new_beam.direction = Direction::North;
+                    return Some(new_beam);
+                }
+                _ => {}
+            },
+            Spot::SplitHori => match self.direction {
+                Direction::North | Direction::South => {
+                    let mut new_beam = *self;
+                    self.direction = Direction::West; // << ---
+                    new_beam.direction = Direction::East;
+                    return Some(new_beam);
+                }
+                _ => {}
+            },
+        };
+        None
+    }
+}
+
+pub fn parse(input: &str) -> Result<Contraption, ParseError> {
+    let value = Grid::parse(&Source::new(Day16::DAY, input), input)?;
+    Ok(Contraption { value })
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = Contraption;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day16 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+impl Animate for Day16 {
+    fn frames(input: &Self::Input<'_>) -> Vec<Image> {
+        render::frames(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_unknown_spot() {
+        let err = parse(".|..\\\n|.+.").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(16, 2, 3, "'.', a mirror or a splitter")
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_core::{Event, Image, Paint, Rgb, Step};
+
+use crate::{step::Beams, Contraption, Spot};
+
+const ENERGIZED: Rgb = Rgb(249, 226, 175);
+const BEAM: Rgb = Rgb(243, 139, 168);
+
+impl Paint for Spot {
+    fn paint(&self) -> Rgb {
+        match self {
+            Spot::Empty => Rgb(30, 30, 46),
+            Spot::BackMirror | Spot::ForwardMirror => Rgb(137, 180, 250),
+            Spot::SplitVert | Spot::SplitHori => Rgb(166, 227, 161),
+        }
+    }
+}
+
+fn on_grid((x, y): (i64, i64)) -> (usize, usize) {
+    (x as usize, y as usize)
+}
+
+/// Every beam moving one tile per frame from the top left, leaving energized tiles behind.
+pub fn frames(contraption: &Contraption) -> Vec<Image> {
+    let base = Image::new(&contraption.value);
+    let mut beams = Beams::new(contraption);
+    let mut frames = vec![base.clone()];
+    loop {
+        let mut frame = base.clone();
+        frame.overlay(beams.energized().iter().copied().map(on_grid), ENERGIZED);
+        frame.overlay(
+            beams.beams().iter().map(|beam| on_grid(beam.position)),
+            BEAM,
+        );
+        frames.push(frame);
+
+        if beams.step() == Event::Finished {
+            return frames;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1};
+
+    #[test]
+    fn ends_with_every_energized_tile() {
+        let contraption = parse(include_str!("../../examples/16/example.txt")).unwrap();
+        let frames = frames(&contraption);
+        let last = frames.last().unwrap();
+
+        let lit = (0..last.height())
+            .flat_map(|y| (0..last.width()).map(move |x| (x, y)))
+            .filter(|&(x, y)| last.get(x, y) == Some(ENERGIZED))
+            .count();
+        assert_eq!(lit as u64, part1::process(&contraption));
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use colored::Colorize;
+
+use aoc_core::{Direction, Event, Step, Tile};
+
+use crate::{Beam, Contraption, Position};
+
+/// Beams spreading from the top left corner, every beam moving one tile per step.
+#[derive(Debug, Clone)]
+pub struct Beams<'a> {
+    contraption: &'a Contraption,
+    beams: Vec<Beam>,
+    seen: HashSet<(Position, Direction)>,
+    energized: HashSet<Position>,
+}
+
+impl<'a> Beams<'a> {
+    pub fn new(contraption: &'a Contraption) -> Self {
+        let mut start = Beam {
+            position: (0, 0),
+            direction: Direction::East,
+        };
+        // the first tile can already turn or split the beam
+        let beams = match contraption.get(start.position) {
+            Some(spot) => start.evaluate(spot).into_iter().chain([start]).collect(),
+            None => Vec::new(),
+        };
+
+        let mut state = Self {
+            contraption,
+            beams: Vec::new(),
+            seen: HashSet::new(),
+            energized: HashSet::new(),
+        };
+        state.enter(beams);
+        state
+    }
+
+    /// Beams still moving.
+    pub fn beams(&self) -> &[Beam] {
+        &self.beams
+    }
+
+    /// Tiles a beam has passed through so far.
+    pub fn energized(&self) -> &HashSet<Position> {
+        &self.energized
+    }
+
+    /// Keeps the beams that are not retracing an earlier beam.
+    fn enter(&mut self, mut beams: Vec<Beam>) {
+        beams.retain(|beam| self.seen.insert((beam.position, beam.direction)));
+        self.energized
+            .extend(beams.iter().map(|beam| beam.position));
+        self.beams = beams;
+    }
+}
+
+impl Step for Beams<'_> {
+    fn step(&mut self) -> Event {
+        if self.beams.is_empty() {
+            return Event::Finished;
+        }
+
+        let mut next = Vec::new();
+        let mut splits = Vec::new();
+        for mut beam in self.beams.drain(..) {
+            beam.step();
+            let Some(spot) = self.contraption.get(beam.position) else {
+                continue;
+            };
+            if let Some(split) = beam.evaluate(spot) {
+                splits.push(beam.position);
+                next.push(split);
+            }
+            next.push(beam);
+        }
+        self.enter(next);
+
+        match splits.as_slice() {
+            [] => Event::Quiet,
+            splits => Event::Breakpoint(format!("beam split at {splits:?}")),
+        }
+    }
+
+    fn view(&self) -> String {
+        let mut out = format!(
+            "{} beams, {} tiles energized\n",
+            self.beams.len(),
+            self.energized.len()
+        );
+        for (y, row) in self.contraption.value.rows().enumerate() {
+            for (x, spot) in row.iter().enumerate() {
+                let position = (x as i64, y as i64);
+                let beam = self.beams.iter().find(|beam| beam.position == position);
+                let tile = match beam {
+                    Some(beam) => match beam.direction {
+                        Direction::North => "^",
+                        Direction::East => ">",
+                        Direction::South => "v",
+                        Direction::West => "<",
+                    }
+                    .red()
+                    .bold(),
+                    None => spot.to_char().to_string().normal(),
+                };
+                let tile = if self.energized.contains(&position) {
+                    tile.on_yellow()
+                } else {
+                    tile
+                };
+                out.push_str(&tile.to_string());
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1};
+
+    #[test]
+    fn energizes_the_same_tiles_as_part_one() {
+        let contraption = parse(include_str!("../../examples/16/example.txt")).unwrap();
+        let mut beams = Beams::new(&contraption);
+        while beams.step() != Event::Finished {}
+        assert!(beams.beams().is_empty());
+        assert_eq!(beams.energized().len() as u64, part1::process(&contraption));
+    }
+}
@@ -1,6 +0,0 @@
-use day_17::part1;
-
-pub fn main() {
-    let input = include_str!("../../input-1-alex-1.txt");
-    println!("Result: {:?}", part1::process(input));
-}
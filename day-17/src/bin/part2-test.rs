@@ -1,6 +0,0 @@
-use day_17::part2;
-
-pub fn main() {
-    let input = include_str!("../../input-1-test.txt");
-    println!("Result: {:?}", part2::process(input));
-}
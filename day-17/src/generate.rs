@@ -0,0 +1,42 @@
+use rand::Rng;
+
+/// A `size` by `size` city of heat losses from 1 to 9, at least five blocks across so
+/// the ultra crucible can reach the far corner.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(5);
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(char::from(b'0' + rng.gen_range(1..=9)));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn both_crucibles_reach_the_far_corner() {
+        for seed in 0..5 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 12);
+            let city = parse(&input).unwrap();
+            // the route is at least 22 blocks, each losing 1 to 9, and a staircase or a
+            // few long runs of 22 blocks is always open to each crucible
+            for loss in [part1::process(&city), part2::process(&city)] {
+                assert!((22..=9 * 22).contains(&loss.unwrap()));
+            }
+        }
+    }
+
+    #[test]
+    fn crosses_a_cool_city_in_a_straight_line() {
+        let city = parse(&"11111\n".repeat(5)).unwrap();
+        assert_eq!(part1::process(&city), Ok(8));
+        assert_eq!(part2::process(&city), Ok(8));
+    }
+}
@@ -1,2 +1,151 @@
+use aoc_core::{
+    Animate, Direction, Generate, Grid, Image, ParseError, SearchState, Solution, Source, Tile,
+    Turn, Unsolvable,
+};
+use rand::Rng;
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+pub mod render;
+
+pub struct Day17;
+
+pub type Position = (usize, usize);
+
+/// Heat lost by entering a city block, from 1 to 9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeatLoss(pub u32);
+
+impl Tile for HeatLoss {
+    const EXPECTED: &'static str = "a digit 1-9";
+
+    fn from_char(c: char) -> Option<Self> {
+        // a block that loses no heat would make the search heuristic overestimate
+        c.to_digit(10).filter(|&loss| loss > 0).map(HeatLoss)
+    }
+
+    fn to_char(&self) -> char {
+        char::from_digit(self.0, 10).unwrap()
+    }
+}
+
+pub type City = Grid<HeatLoss>;
+
+/// A crucible rolling through the city, which must go at least `MIN` blocks in a straight
+/// line before it can turn or stop, and at most `MAX`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Crucible<const MIN: u8, const MAX: u8> {
+    pub position: Position,
+    pub heading: Direction,
+    /// Blocks moved since the last turn.
+    pub run: u8,
+}
+
+impl<const MIN: u8, const MAX: u8> Crucible<MIN, MAX> {
+    /// The top left block, about to head either east or south.
+    pub fn starts() -> [Self; 2] {
+        [Direction::East, Direction::South].map(|heading| Self {
+            position: (0, 0),
+            heading,
+            run: 0,
+        })
+    }
+}
+
+impl<const MIN: u8, const MAX: u8> SearchState for Crucible<MIN, MAX> {
+    type World = City;
+    type Cost = u32;
+
+    fn neighbours(&self, city: &City) -> impl IntoIterator<Item = (Self, u32)> {
+        [Turn::Left, Turn::Straight, Turn::Right]
+            .into_iter()
+            .filter_map(move |turn| {
+                let run = match turn {
+                    Turn::Straight if self.run < MAX => self.run + 1,
+                    Turn::Left | Turn::Right if self.run >= MIN => 1,
+                    _ => return None,
+                };
+                let heading = self.heading.turn(turn);
+                let position = heading.checked_step(self.position)?;
+                let loss = city.get(position.0, position.1)?.0;
+                Some((
+                    Self {
+                        position,
+                        heading,
+                        run,
+                    },
+                    loss,
+                ))
+            })
+    }
+
+    fn is_goal(&self, city: &City) -> bool {
+        self.position == (city.width() - 1, city.height() - 1) && self.run >= MIN
+    }
+
+    /// Every block loses at least 1 heat, so the distance left is a safe guess.
+    fn heuristic(&self, city: &City) -> u32 {
+        let (x, y) = self.position;
+        (city.width() - 1 - x + city.height() - 1 - y) as u32
+    }
+}
+
+pub fn parse(input: &str) -> Result<City, ParseError> {
+    Grid::parse(&Source::new(Day17::DAY, input), input)
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input<'a> = City;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        part2::process(input)
+    }
+}
+
+impl Generate for Day17 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+impl Animate for Day17 {
+    fn frames(input: &Self::Input<'_>) -> Vec<Image> {
+        render::frames(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_non_digit() {
+        let err = parse("241\n3x1").unwrap_err();
+        assert_eq!(err, ParseError::new(17, 2, 2, "a digit 1-9"));
+    }
+
+    #[test]
+    fn reports_block_without_heat_loss() {
+        let err = parse("00\n01\n01").unwrap_err();
+        assert_eq!(err, ParseError::new(17, 1, 1, "a digit 1-9"));
+    }
+
+    #[test]
+    fn reports_ragged_row() {
+        let err = parse("241\n32\n111").unwrap_err();
+        assert_eq!(err, ParseError::new(17, 2, 1, "a row of 3 tiles"));
+    }
+}
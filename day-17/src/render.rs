@@ -0,0 +1,66 @@
+use aoc_core::{astar, Image, Paint, Rgb};
+
+use crate::{City, Crucible, HeatLoss};
+
+const PATH: Rgb = Rgb(243, 139, 168);
+
+impl Paint for HeatLoss {
+    fn paint(&self) -> Rgb {
+        // blocks that lose more heat are drawn brighter
+        let shade = 30 + 20 * self.0 as u8;
+        Rgb(shade, shade, shade + 16)
+    }
+}
+
+/// The city, then the crucible's least lossy route from part 1 one block per frame.
+pub fn frames(city: &City) -> Vec<Image> {
+    let base = Image::new(city);
+    let Some(found) = astar(city, Crucible::<1, 3>::starts()) else {
+        return vec![base];
+    };
+
+    let mut frame = base;
+    let mut frames = Vec::with_capacity(found.path.len());
+    for crucible in found.path {
+        frame.overlay([crucible.position], PATH);
+        frames.push(frame.clone());
+    }
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1};
+
+    #[test]
+    fn traces_the_route_over_the_city() {
+        let city = parse(include_str!("../../examples/17/example.txt")).unwrap();
+        let frames = frames(&city);
+        let last = frames.last().unwrap();
+
+        let lost = (0..last.height())
+            .flat_map(|y| (0..last.width()).map(move |x| (x, y)))
+            .filter(|&(x, y)| (x, y) != (0, 0) && last.get(x, y) == Some(PATH))
+            .map(|(x, y)| city.get(x, y).unwrap().0)
+            .sum::<u32>();
+        assert_eq!(Ok(lost), part1::process(&city));
+    }
+
+    #[test]
+    fn moves_one_block_per_frame() {
+        let city = parse("11\n11").unwrap();
+        let frames = frames(&city);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].get(0, 0), Some(PATH));
+        assert_eq!(frames[0].get(1, 1), Some(HeatLoss(1).paint()));
+        assert_eq!(frames[2].get(1, 1), Some(PATH));
+    }
+
+    #[test]
+    fn draws_only_the_city_without_a_route() {
+        // a lone block is the far corner, but the crucible has to move to stop there
+        let city = parse("1").unwrap();
+        assert_eq!(frames(&city), vec![Image::new(&city)]);
+    }
+}
@@ -0,0 +1,85 @@
+use std::cmp::Ordering;
+
+use aoc_core::outline;
+use rand::Rng;
+
+/// A dig plan round a random tree-shaped lagoon of `size` by `size` rooms; the colour
+/// codes trace the same shape, stretched to distances in the hundreds of thousands.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let points = outline(rng, size);
+    let side = points.iter().map(|&(x, y)| x.max(y)).max().unwrap_or(0) as usize;
+
+    // stretching each unit of the outline keeps it from crossing itself
+    let mut stretch = |most: i64| {
+        let mut at = 0;
+        let mut coordinates = vec![0];
+        for _ in 0..side {
+            at += rng.gen_range(1..=most);
+            coordinates.push(at);
+        }
+        coordinates
+    };
+    let plan = [stretch(3), stretch(3)];
+    let colour = [
+        stretch(0xfffff / side as i64),
+        stretch(0xfffff / side as i64),
+    ];
+
+    let mut out = String::new();
+    let mut corners = points
+        .iter()
+        .enumerate()
+        .filter(|&(i, &at)| {
+            let before = points[(i + points.len() - 1) % points.len()];
+            let after = points[(i + 1) % points.len()];
+            before.0 - at.0 != at.0 - after.0 || before.1 - at.1 != at.1 - after.1
+        })
+        .map(|(_, &at)| at)
+        .collect::<Vec<_>>();
+    corners.push(corners[0]);
+
+    for pair in corners.windows(2) {
+        let [from, to] = [pair[0], pair[1]].map(|(x, y)| (x as usize, y as usize));
+        let distance = |[xs, ys]: &[Vec<i64>; 2]| {
+            (xs[to.0] - xs[from.0]).abs() + (ys[to.1] - ys[from.1]).abs()
+        };
+        let (letter, digit) = match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+            (Ordering::Greater, _) => ('R', 0),
+            (Ordering::Less, _) => ('L', 2),
+            (_, Ordering::Greater) => ('D', 1),
+            _ => ('U', 3),
+        };
+        out.push_str(&format!(
+            "{letter} {} (#{:05x}{digit})\n",
+            distance(&plan),
+            distance(&colour)
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn plans_close_up() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 5);
+            let steps = parse(&input).unwrap();
+            let plans = steps.iter().map(|s| &s.plan).collect::<Vec<_>>();
+            let colours = steps.iter().map(|s| &s.color).collect::<Vec<_>>();
+            for commands in [plans, colours] {
+                let end = commands.iter().fold((0, 0), |(x, y), c| {
+                    let (dx, dy) = c.direction.delta();
+                    (x + dx as i128 * c.distance, y + dy as i128 * c.distance)
+                });
+                assert_eq!(end, (0, 0), "seed {seed}");
+            }
+            assert!(part1::process(&steps) > 0);
+            assert!(part2::process(&steps) > part1::process(&steps));
+        }
+    }
+}
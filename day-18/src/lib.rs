@@ -1,2 +1,137 @@
+use aoc_core::{
+    parsers::{expect, integer, lines, tag, IResult},
+    Direction, Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{
+    bytes::complete::take_while_m_n,
+    character::complete::anychar,
+    combinator::{map, map_opt},
+    sequence::{delimited, pair, separated_pair},
+};
+use rand::Rng;
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+pub struct Day18;
+
+#[derive(Debug, PartialEq)]
+pub struct DigCommand {
+    pub direction: Direction,
+    pub distance: i128,
+}
+
+/// One line of the dig plan, read both ways: as written, and decoded from its colour.
+#[derive(Debug, PartialEq)]
+pub struct DigStep {
+    pub plan: DigCommand,
+    pub color: DigCommand,
+}
+
+pub fn parse(input: &str) -> Result<Vec<DigStep>, ParseError> {
+    Source::new(Day18::DAY, input).run(lines(parse_into_step), input)
+}
+
+// R 6 (#70c710)
+fn parse_into_step(input: &str) -> IResult<'_, DigStep> {
+    let direction = expect(
+        "'R', 'L', 'U' or 'D'",
+        map_opt(anychar, Direction::from_udlr),
+    );
+    let distance = expect("a distance", integer);
+    let plan = map(
+        separated_pair(direction, tag(" "), distance),
+        |(direction, distance)| DigCommand {
+            direction,
+            distance,
+        },
+    );
+
+    let hex_distance = expect(
+        "a hex distance",
+        map_opt(
+            take_while_m_n(5, 5, |c: char| c.is_ascii_hexdigit()),
+            |hex| i128::from_str_radix(hex, 16).ok(),
+        ),
+    );
+    let hex_direction = expect(
+        "a direction digit from 0 to 3",
+        map_opt(anychar, Direction::from_hex_digit),
+    );
+    let color = map(
+        delimited(tag("(#"), pair(hex_distance, hex_direction), tag(")")),
+        |(distance, direction)| DigCommand {
+            direction,
+            distance,
+        },
+    );
+
+    map(separated_pair(plan, tag(" "), color), |(plan, color)| {
+        DigStep { plan, color }
+    })(input)
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<DigStep>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day18 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_both_commands() {
+        let result = parse("R 6 (#70c710)").unwrap();
+        assert_eq!(
+            result,
+            vec![DigStep {
+                plan: DigCommand {
+                    direction: Direction::East,
+                    distance: 6,
+                },
+                color: DigCommand {
+                    direction: Direction::East,
+                    distance: 461937,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_unknown_direction() {
+        let err = parse("R 6 (#70c710)\nD 5 (#0dc571)\nX 2 (#5713f0)").unwrap_err();
+        assert_eq!(err, ParseError::new(18, 3, 1, "'R', 'L', 'U' or 'D'"));
+    }
+
+    #[test]
+    fn reports_unknown_hex_direction() {
+        let err = parse("R 6 (#70c714)").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(18, 1, 12, "a direction digit from 0 to 3")
+        );
+    }
+}
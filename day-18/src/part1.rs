@@ -1,61 +1,19 @@
-pub fn process(input: &'static str) -> u64 {
-    part1(input)
-}
-
-#[derive(Debug)]
-enum Direction {
-    Right,
-    Left,
-    Up,
-    Down,
-}
-
-#[derive(Debug)]
-struct DigCommand {
-    direction: Direction,
-    distance: u8,
-}
-
-type DigBlueprint = Vec<DigCommand>;
-
-fn parse_into_command(input: &'static str) -> DigCommand {
-    let mut spl = input.split(" ");
-    let direction = match spl.next() {
-        Some("R") => Direction::Right,
-        Some("L") => Direction::Left,
-        Some("U") => Direction::Up,
-        Some("D") => Direction::Down,
-        _ => panic!("Invalid direction"),
-    };
-    let distance = spl.next().unwrap().parse::<u8>().unwrap();
-
-    DigCommand {
-        direction,
-        distance,
-    }
-}
-
-fn parse_input(input: &'static str) -> DigBlueprint {
-    input
-        .lines()
-        .map(|line| parse_into_command(line.trim()))
-        .collect::<Vec<_>>()
-}
+use aoc_core::Direction;
 
-fn part1(input: &'static str) -> u64 {
-    let instructions = parse_input(input);
+use crate::DigStep;
 
+pub fn process(steps: &[DigStep]) -> u64 {
     let mut border_count = 0;
     let mut x_val = 0;
     let mut nominal_area = 0;
 
-    instructions.iter().for_each(|com| {
-        border_count += com.distance as i32;
+    steps.iter().map(|step| &step.plan).for_each(|com| {
+        border_count += com.distance;
         match com.direction {
-            Direction::Left => x_val -= com.distance as i32,
-            Direction::Right => x_val += com.distance as i32,
-            Direction::Up => nominal_area -= x_val * (com.distance as i32),
-            Direction::Down => nominal_area += x_val * (com.distance as i32),
+            Direction::West => x_val -= com.distance,
+            Direction::East => x_val += com.distance,
+            Direction::North => nominal_area -= x_val * com.distance,
+            Direction::South => nominal_area += x_val * com.distance,
         }
     });
     (border_count / 2 + nominal_area + 1) as u64
@@ -63,12 +21,48 @@ fn part1(input: &'static str) -> u64 {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use super::*;
+    use crate::{generate::generate, parse};
+    use proptest::prelude::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// Digs the trench out one cube at a time and floods the ground around it from outside.
+    fn dig_out(steps: &[DigStep]) -> u64 {
+        let mut trench = HashSet::from([(0, 0)]);
+        let mut at = (0, 0);
+        for command in steps.iter().map(|step| &step.plan) {
+            for _ in 0..command.distance {
+                at = command.direction.step(at);
+                trench.insert(at);
+            }
+        }
+
+        let min_x = trench.iter().map(|p| p.0).min().unwrap() - 1;
+        let max_x = trench.iter().map(|p| p.0).max().unwrap() + 1;
+        let min_y = trench.iter().map(|p| p.1).min().unwrap() - 1;
+        let max_y = trench.iter().map(|p| p.1).max().unwrap() + 1;
+
+        let mut outside = HashSet::from([(min_x, min_y)]);
+        let mut frontier = vec![(min_x, min_y)];
+        while let Some(at) = frontier.pop() {
+            for next in Direction::ALL.map(|dir| dir.step(at)) {
+                let on_map = (min_x..=max_x).contains(&next.0) && (min_y..=max_y).contains(&next.1);
+                if on_map && !trench.contains(&next) && outside.insert(next) {
+                    frontier.push(next);
+                }
+            }
+        }
+
+        ((max_x - min_x + 1) * (max_y - min_y + 1)) as u64 - outside.len() as u64
+    }
 
     #[test]
     fn it_works() {
         let result = process(
-            "R 6 (#70c710)
+            &parse(
+                "R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
 D 2 (#d2c081)
@@ -82,7 +76,20 @@ R 2 (#7807d2)
 U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)",
+            )
+            .unwrap(),
         );
         assert_eq!(result, 62);
     }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn shoelace_matches_digging_out(seed: u64, size in 1_usize..6) {
+            let input = generate(&mut StdRng::seed_from_u64(seed), size);
+            let steps = parse(&input).unwrap();
+            prop_assert_eq!(process(&steps), dig_out(&steps));
+        }
+    }
 }
@@ -0,0 +1,91 @@
+use std::collections::{HashSet, VecDeque};
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Up to `size` workflows branching out from `in` without ever looping back, followed by `size`
+/// parts with ratings from 1 to 4000.
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut used = HashSet::from(["in".to_string()]);
+    let mut waiting = VecDeque::from(["in".to_string()]);
+    let mut budget = size.max(1) - 1;
+    let mut workflows = Vec::new();
+
+    while let Some(name) = waiting.pop_front() {
+        let mut send = |rng: &mut R| {
+            if budget > 0 && rng.gen_ratio(3, 5) {
+                budget -= 1;
+                let next = loop {
+                    let next = (0..rng.gen_range(2..=3))
+                        .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+                        .collect::<String>();
+                    if used.insert(next.clone()) {
+                        break next;
+                    }
+                };
+                waiting.push_back(next.clone());
+                next
+            } else if rng.gen() {
+                "A".to_string()
+            } else {
+                "R".to_string()
+            }
+        };
+
+        let mut rules = (0..rng.gen_range(1..=3))
+            .map(|_| {
+                let category = *b"xmas".choose(rng).unwrap() as char;
+                let operation = if rng.gen() { '<' } else { '>' };
+                let value = rng.gen_range(1..=4000);
+                format!("{category}{operation}{value}:{}", send(rng))
+            })
+            .collect::<Vec<_>>();
+        rules.push(send(rng));
+        workflows.push(format!("{name}{{{}}}", rules.join(",")));
+    }
+    workflows.shuffle(rng);
+
+    let parts = (0..size).map(|_| {
+        let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+        format!("{{x={x},m={m},a={a},s={s}}}")
+    });
+    let parts = parts.collect::<Vec<_>>();
+
+    format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, part2, PipeTo};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn every_workflow_is_sent_to_once() {
+        for seed in 0..10 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 30);
+            // parse rejects a rule sending parts to a workflow that isn't there
+            let system = parse(&input).unwrap();
+            assert!(system.workflows.len() <= 30);
+            assert_eq!(system.parts.len(), 30);
+
+            // and with nothing sent to twice or back to `in`, the workflows never loop
+            let sent_to = system
+                .workflows
+                .values()
+                .flatten()
+                .filter_map(|step| match step.pipe_to {
+                    PipeTo::Next(name) => Some(name),
+                    PipeTo::Final(_) => None,
+                })
+                .collect::<Vec<_>>();
+            let unique = sent_to.iter().collect::<HashSet<_>>();
+            assert_eq!(unique.len(), sent_to.len());
+            assert_eq!(sent_to.len(), system.workflows.len() - 1);
+            assert!(!unique.contains(&"in"));
+
+            let ratings = system.parts.iter().map(|p| p.x + p.m + p.a + p.s).sum();
+            assert!(part1::process(&system) <= ratings);
+            assert!(part2::process(&system) <= 4000_u64.pow(4));
+        }
+    }
+}
@@ -1,2 +1,321 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{
+    parsers::{blank_line, expect, integer, lines, tag, Expected, IResult},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    character::complete::{alpha1, anychar, one_of},
+    combinator::{cut, map, map_res, opt, peek, recognize},
+    multi::{separated_list0, separated_list1},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    Err,
+};
+use rand::Rng;
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+pub struct Day19;
+
+#[derive(Debug, Clone, Copy)]
+pub enum PartParam {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<&str> for PartParam {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "x" => Ok(PartParam::X),
+            "m" => Ok(PartParam::M),
+            "a" => Ok(PartParam::A),
+            "s" => Ok(PartParam::S),
+            _ => Err("Invalid part param"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Operation {
+    Less,
+    Greater,
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "<" => Ok(Operation::Less),
+            ">" => Ok(Operation::Greater),
+            _ => Err("Invalid operation"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FinalAction {
+    Accept,
+    Reject,
+}
+
+impl TryFrom<&str> for FinalAction {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "A" => Ok(FinalAction::Accept),
+            "R" => Ok(FinalAction::Reject),
+            _ => Err("Invalid action"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PipeTo<'a> {
+    Next(&'a str),
+    Final(FinalAction),
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    pub part: PartParam,
+    pub operation: Operation,
+    pub compare: u64,
+}
+
+#[derive(Debug)]
+pub struct WorkflowStep<'a> {
+    pub check: Option<Rule>,
+    pub pipe_to: PipeTo<'a>,
+}
+
+#[derive(Debug)]
+pub struct Part {
+    pub x: u64,
+    pub m: u64,
+    pub a: u64,
+    pub s: u64,
+}
+
+#[derive(Debug)]
+pub struct System<'a> {
+    pub workflows: HashMap<&'a str, Vec<WorkflowStep<'a>>>,
+    pub parts: Vec<Part>,
+}
+
+pub fn parse(input: &str) -> Result<System<'_>, ParseError> {
+    let system = separated_pair(lines(parse_workflow), blank_line, lines(parse_part));
+    let source = Source::new(Day19::DAY, input);
+    let (workflows, parts) = source.run(system, input)?;
+
+    // every part starts at `in`, and every rule has to send it somewhere that exists
+    let names = workflows
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<HashSet<_>>();
+    if !names.contains("in") {
+        return Err(source.error(input, "a workflow named \"in\""));
+    }
+    let targets = workflows.iter().flat_map(|(_, steps)| steps);
+    for step in targets {
+        if let PipeTo::Next(name) = step.pipe_to {
+            if !names.contains(name) {
+                return Err(source.error(name, "a workflow defined above"));
+            }
+        }
+    }
+
+    // a part sent back to a workflow it has already been through would go round forever
+    let order = workflows.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    let workflows = workflows.into_iter().collect::<HashMap<_, _>>();
+    let (mut following, mut settled) = (HashSet::new(), HashSet::new());
+    for name in order {
+        if let Some(back) = find_loop(name, &workflows, &mut following, &mut settled) {
+            return Err(source.error(back, "a workflow that doesn't loop back"));
+        }
+    }
+
+    Ok(System { workflows, parts })
+}
+
+/// Follows every workflow `name` can send parts to, returning the first reference back to
+/// one still being followed. `settled` holds those already known to lead only to A or R.
+fn find_loop<'a>(
+    name: &'a str,
+    workflows: &HashMap<&'a str, Vec<WorkflowStep<'a>>>,
+    following: &mut HashSet<&'a str>,
+    settled: &mut HashSet<&'a str>,
+) -> Option<&'a str> {
+    if settled.contains(name) {
+        return None;
+    }
+    following.insert(name);
+    for step in &workflows[name] {
+        if let PipeTo::Next(next) = step.pipe_to {
+            if following.contains(next) {
+                return Some(next);
+            }
+            if let Some(back) = find_loop(next, workflows, following, settled) {
+                return Some(back);
+            }
+        }
+    }
+    following.remove(name);
+    settled.insert(name);
+    None
+}
+
+// {x=787,m=2655,a=1222,s=2876}
+fn parse_part(input: &str) -> IResult<'_, Part> {
+    let field = pair(alpha1, preceded(tag("="), cut(expect("a rating", integer))));
+    let (rest, fields) = preceded(tag("{"), separated_list0(tag(","), field))(input)?;
+
+    // every rating must be there, in order
+    let mut fields = fields.into_iter();
+    let mut rating = |name: &'static str| match fields.next() {
+        Some((found, value)) if found == name => Ok(value),
+        Some((found, _)) => Err(Err::Failure(Expected::new(found, format!("\"{name}=\"")))),
+        None => Err(Err::Failure(Expected::new(rest, format!("\"{name}=\"")))),
+    };
+    let part = Part {
+        x: rating("x")?,
+        m: rating("m")?,
+        a: rating("a")?,
+        s: rating("s")?,
+    };
+    let (rest, _) = tag("}")(rest)?;
+    Ok((rest, part))
+}
+
+// a<2006:qkq
+fn parse_instruction(input: &str) -> IResult<'_, WorkflowStep<'_>> {
+    let part = expect(
+        "'x', 'm', 'a' or 's'",
+        map_res(recognize(anychar), PartParam::try_from),
+    );
+    let operation = map_res(recognize(one_of("<>")), Operation::try_from);
+    let rule = map(
+        tuple((part, operation, expect("a number", integer))),
+        |(part, operation, compare)| Rule {
+            part,
+            operation,
+            compare,
+        },
+    );
+    // anything followed by '<' or '>' is a rule, which then has to be a good one
+    let check = opt(preceded(
+        peek(pair(anychar, one_of("<>"))),
+        cut(terminated(rule, tag(":"))),
+    ));
+
+    let pipe_to = expect(
+        "a workflow name, 'A' or 'R'",
+        alt((
+            map_res(alpha1, |s| FinalAction::try_from(s).map(PipeTo::Final)),
+            map(take_while1(|c: char| c.is_ascii_lowercase()), PipeTo::Next),
+        )),
+    );
+    map(pair(check, pipe_to), |(check, pipe_to)| WorkflowStep {
+        check,
+        pipe_to,
+    })(input)
+}
+
+// px{a<2006:qkq,m>2090:A,rfg}
+fn parse_workflow(input: &str) -> IResult<'_, (&str, Vec<WorkflowStep<'_>>)> {
+    let steps = separated_list1(tag(","), cut(parse_instruction));
+    pair(alpha1, delimited(tag("{"), steps, tag("}")))(input)
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input<'a> = System<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        Ok(part2::process(input))
+    }
+}
+
+impl Generate for Day19 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_unknown_category() {
+        let err = parse("in{a<2006:qkq,y>2090:A,rfg}\n\n{x=787,m=2655,a=1222,s=2876}").unwrap_err();
+        assert_eq!(err, ParseError::new(19, 1, 15, "'x', 'm', 'a' or 's'"));
+    }
+
+    #[test]
+    fn reports_missing_rating() {
+        let err = parse("in{A}\n\n{x=787,m=2655,a=1222}").unwrap_err();
+        assert_eq!(err, ParseError::new(19, 3, 21, "\"s=\""));
+    }
+
+    #[test]
+    fn reports_undefined_workflow() {
+        let err = parse(
+            "in{a<2006:qkq,A}
+
+{x=787,m=2655,a=1222,s=2876}",
+        )
+        .unwrap_err();
+        assert_eq!(err, ParseError::new(19, 1, 11, "a workflow defined above"));
+
+        let err = parse(
+            "px{A}
+
+{x=787,m=2655,a=1222,s=2876}",
+        )
+        .unwrap_err();
+        assert_eq!(err, ParseError::new(19, 1, 1, "a workflow named \"in\""));
+    }
+
+    #[test]
+    fn reports_workflow_loop() {
+        let err = parse("in{x<2000:in,A}\n\n{x=787,m=2655,a=1222,s=2876}").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(19, 1, 11, "a workflow that doesn't loop back")
+        );
+
+        let err = parse(
+            "in{a<2006:px,A}
+px{m>2090:A,qs}
+qs{px}
+
+{x=787,m=2655,a=1222,s=2876}",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(19, 3, 4, "a workflow that doesn't loop back")
+        );
+    }
+}
@@ -1,6 +0,0 @@
-use day_20::part1;
-
-pub fn main() {
-    let input = include_str!("../../input-1-test-2.txt");
-    println!("Result: {:?}", part1::process(input));
-}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+/// Four counters of `size` flip-flops each, up to twelve as in the real puzzle, feeding `rx`
+/// through a final conjunction so that part two has an answer.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let bits = size.clamp(2, 12);
+    // odd, with the top bit set, so every flip-flop takes part in the count
+    let periods = (0..4)
+        .map(|_| rng.gen_range(1 << (bits - 2)..1 << (bits - 1)) * 2 + 1)
+        .collect::<Vec<_>>();
+    counters(rng, bits, &periods)
+}
+
+/// Binary counters that each send `rx`'s feeder a high pulse every `periods[i]` presses.
+///
+/// A counter is a chain of flip-flops; those for the period's set bits report to a
+/// conjunction, which once they are all on resets the count by setting every other bit.
+fn counters(rng: &mut impl Rng, bits: usize, periods: &[u32]) -> String {
+    let mut used = HashSet::from(["rx".to_string()]);
+    let mut name = || loop {
+        let name = (0..2)
+            .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+            .collect::<String>();
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let feeder = name();
+    let mut lines = vec![(format!("&{feeder}"), vec!["rx".to_string()])];
+    let mut starts = Vec::new();
+    for &period in periods {
+        let chain = (0..bits).map(|_| name()).collect::<Vec<_>>();
+        let (counter, inverter) = (name(), name());
+
+        let mut resets = vec![chain[0].clone()];
+        for (bit, flip_flop) in chain.iter().enumerate() {
+            let mut outputs = chain.get(bit + 1).cloned().into_iter().collect::<Vec<_>>();
+            if period & 1 << bit != 0 {
+                outputs.push(counter.clone());
+            } else {
+                resets.push(flip_flop.clone());
+            }
+            lines.push((format!("%{flip_flop}"), outputs));
+        }
+        resets.push(inverter.clone());
+        lines.push((format!("&{counter}"), resets));
+        lines.push((format!("&{inverter}"), vec![feeder.clone()]));
+        starts.push(chain[0].clone());
+    }
+    lines.push(("broadcaster".to_string(), starts));
+    lines.shuffle(rng);
+
+    lines
+        .into_iter()
+        .map(|(module, mut outputs)| {
+            outputs.shuffle(rng);
+            format!("{module} -> {}\n", outputs.join(", "))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part2, press_button, PulseType};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn counters_reach_rx_together() {
+        for seed in 0..3 {
+            let input = counters(&mut StdRng::seed_from_u64(seed), 4, &[9, 11, 13, 15]);
+            let mut relays = parse(&input).unwrap();
+            assert_eq!(part2::process(&relays), Ok(9 * 11 * 13 * 5));
+
+            // and pressing the button that many times really does get a low pulse to rx
+            let presses = (1..)
+                .find(|_| {
+                    press_button(&mut relays)
+                        .iter()
+                        .any(|pulse| pulse.to == "rx" && pulse.r#type == PulseType::Low)
+                })
+                .unwrap();
+            assert_eq!(presses, 9 * 11 * 13 * 5);
+        }
+    }
+
+    #[test]
+    fn rx_waits_on_every_counter() {
+        for seed in 0..3 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 12);
+            let presses = part2::process(&parse(&input).unwrap()).unwrap();
+            // each period is odd with the top of its twelve bits set, and so is their product
+            assert_eq!(presses % 2, 1);
+            assert!(presses > 1 << 11);
+        }
+    }
+}
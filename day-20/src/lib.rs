@@ -1,2 +1,218 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt::{Display, Formatter},
+};
+
+use aoc_core::{
+    parsers::{edge, expect, lines, tag, IResult},
+    Generate, ParseError, Solution, Source, Unsolvable,
+};
+use nom::{branch::alt, character::complete::alpha1, combinator::map, sequence::preceded};
+use rand::Rng;
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+pub mod step;
+
+pub struct Day20;
+
+pub type RelaySet<'a> = BTreeMap<&'a str, Relay<'a>>;
+
+/// Tells every conjunction which modules send to it, all starting low.
+fn prime_relay_conjunctions(map: &mut RelaySet) {
+    map.iter()
+        .flat_map(|(sender, v)| v.output.iter().map(|receiver| (*sender, *receiver)))
+        // need this block due to borrow checker
+        // TODO: how do I get around this?
+        .collect::<Vec<_>>()
+        .iter()
+        //
+        .for_each(|(sender, receiver)| {
+            let _entry = map.entry(receiver).and_modify(|relay| {
+                if let Module::Conjunction(ref mut h) = relay.module {
+                    h.insert(sender, false);
+                }
+            });
+            // match entry {
+            //     Entry::Occupied(_) => (),
+            //     _ => panic!("entry must exist, or Input is broken"),
+            // };
+        });
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Module<'a> {
+    Broadcaster,
+    FlipFlop(bool),
+    Conjunction(BTreeMap<&'a str, bool>),
+}
+
+impl<'a> Module<'a> {
+    pub fn fire(&mut self, pulse: &Pulse<'a>) -> Option<PulseType> {
+        match self {
+            Self::Broadcaster => Some(pulse.r#type),
+            Self::FlipFlop(state) => match pulse.r#type {
+                PulseType::High => None,
+                PulseType::Low => {
+                    *state = !*state;
+                    Some(state.then(|| PulseType::High).unwrap_or(PulseType::Low))
+                }
+            },
+            Self::Conjunction(ref mut state) => {
+                state
+                    .entry(pulse.from)
+                    .and_modify(|v| *v = matches!(pulse.r#type, PulseType::High));
+                let x = if state.iter().all(|(_, v)| *v) {
+                    PulseType::Low
+                } else {
+                    PulseType::High
+                };
+                Some(x)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relay<'a> {
+    pub output: Vec<&'a str>,
+    pub module: Module<'a>,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum PulseType {
+    High,
+    Low,
+}
+
+impl Display for PulseType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PulseType::High => write!(f, "high"),
+            PulseType::Low => write!(f, "low"),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<RelaySet<'_>, ParseError> {
+    let relays = Source::new(Day20::DAY, input).run(lines(parse_relay), input)?;
+    let mut map = relays.into_iter().collect();
+
+    prime_relay_conjunctions(&mut map);
+    Ok(map)
+}
+
+// %fx -> kh, hl
+fn parse_relay(input: &str) -> IResult<'_, (&str, Relay<'_>)> {
+    let module = expect(
+        "'broadcaster', '&' or '%'",
+        alt((
+            map(tag("broadcaster"), |name| (name, Module::Broadcaster)),
+            map(preceded(tag("&"), alpha1), |name| {
+                (name, Module::Conjunction(BTreeMap::new()))
+            }),
+            map(preceded(tag("%"), alpha1), |name| {
+                (name, Module::FlipFlop(false))
+            }),
+        )),
+    );
+    map(edge(module, alpha1), |((name, module), output)| {
+        (name, Relay { output, module })
+    })(input)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pulse<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    pub r#type: PulseType,
+}
+
+/// Presses the button once, returning every pulse sent in the order they were sent.
+pub fn press_button<'a>(map: &mut RelaySet<'a>) -> Vec<Pulse<'a>> {
+    let mut queue = VecDeque::from([Pulse {
+        from: "button",
+        to: "broadcaster",
+        r#type: PulseType::Low,
+    }]);
+    let mut sent = Vec::new();
+
+    while let Some(pulse) = queue.pop_front() {
+        sent.push(pulse);
+
+        let Some(relay) = map.get_mut(pulse.to) else {
+            continue;
+        };
+
+        // fire the relay module, and if it gets a result, fan out
+        if let Some(pulse_type) = relay.module.fire(&pulse) {
+            for to in relay.output.iter() {
+                queue.push_back(Pulse {
+                    from: pulse.to,
+                    to,
+                    r#type: pulse_type,
+                });
+            }
+        }
+    }
+    sent
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input<'a> = RelaySet<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        part2::process(input)
+    }
+}
+
+impl Generate for Day20 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("%fx -> kh, hl", ("fx",Relay {
+        output: vec!["kh", "hl"],
+        module: Module::FlipFlop(false),
+    }))]
+    #[case("broadcaster -> kh, hl", ("broadcaster", Relay {
+        output: vec!["kh", "hl"],
+        module: Module::Broadcaster,
+    }))]
+    #[case("&hr -> kh, hl", ("hr", Relay {
+        output: vec!["kh", "hl"],
+        module: Module::Conjunction(BTreeMap::new()),
+    }))]
+    fn test_parse_module_config(
+        #[case] data: &'static str,
+        #[case] expected: (&'static str, Relay),
+    ) {
+        assert_eq!(Ok(("", expected)), parse_relay(data));
+    }
+
+    #[test]
+    fn reports_unknown_module() {
+        let err = parse("broadcaster -> a\n%a -> b\n$b -> a").unwrap_err();
+        assert_eq!(err, ParseError::new(20, 3, 1, "'broadcaster', '&' or '%'"));
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt::Write;
+
+use colored::Colorize;
+
+use aoc_core::{Event, Step};
+
+use crate::{press_button, Module, Pulse, PulseType, RelaySet};
+
+/// Pulses sent after this many are left out of the view.
+const SHOWN_PULSES: usize = 40;
+
+/// The modules between button presses, one press per step.
+#[derive(Debug, Clone)]
+pub struct Presses<'a> {
+    relays: RelaySet<'a>,
+    presses: usize,
+    last: Vec<Pulse<'a>>,
+}
+
+impl<'a> Presses<'a> {
+    pub fn new(relays: &RelaySet<'a>) -> Self {
+        Self {
+            relays: relays.clone(),
+            presses: 0,
+            last: Vec::new(),
+        }
+    }
+}
+
+fn pulse_type(pulse_type: PulseType) -> colored::ColoredString {
+    match pulse_type {
+        PulseType::High => "high".red(),
+        PulseType::Low => "low".blue(),
+    }
+}
+
+impl Step for Presses<'_> {
+    fn step(&mut self) -> Event {
+        self.last = press_button(&mut self.relays);
+        self.presses += 1;
+
+        if self
+            .last
+            .iter()
+            .any(|pulse| pulse.to == "rx" && pulse.r#type == PulseType::Low)
+        {
+            return Event::Breakpoint(format!("rx got a low pulse on press {}", self.presses));
+        }
+        let all_off = self
+            .relays
+            .values()
+            .all(|relay| relay.module != Module::FlipFlop(true));
+        if all_off {
+            return Event::Breakpoint(format!(
+                "every flip-flop is off again after {} presses",
+                self.presses
+            ));
+        }
+        Event::Quiet
+    }
+
+    fn view(&self) -> String {
+        let high = self
+            .last
+            .iter()
+            .filter(|pulse| pulse.r#type == PulseType::High)
+            .count();
+        let mut out = format!(
+            "press {}: {high} high and {} low pulses\n",
+            self.presses,
+            self.last.len() - high
+        );
+
+        for pulse in self.last.iter().take(SHOWN_PULSES) {
+            writeln!(
+                out,
+                "  {} -{}-> {}",
+                pulse.from,
+                pulse_type(pulse.r#type),
+                pulse.to
+            )
+            .unwrap();
+        }
+        if self.last.len() > SHOWN_PULSES {
+            writeln!(out, "  ... {} more", self.last.len() - SHOWN_PULSES).unwrap();
+        }
+
+        out.push('\n');
+        for (name, relay) in &self.relays {
+            let state = match &relay.module {
+                Module::Broadcaster => continue,
+                Module::FlipFlop(true) => "on".green().to_string(),
+                Module::FlipFlop(false) => "off".dimmed().to_string(),
+                Module::Conjunction(inputs) => inputs
+                    .iter()
+                    .map(|(input, high)| match high {
+                        true => input.red().to_string(),
+                        false => input.blue().to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            };
+            writeln!(out, "{name}: {state}").unwrap();
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn breaks_once_every_flip_flop_is_off() {
+        let relays = parse(include_str!("../../examples/20/example-2.txt")).unwrap();
+        let mut presses = Presses::new(&relays);
+        let events = (0..4).map(|_| presses.step()).collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            vec![
+                Event::Quiet,
+                Event::Quiet,
+                Event::Quiet,
+                Event::Breakpoint("every flip-flop is off again after 4 presses".to_string()),
+            ]
+        );
+    }
+}
@@ -1,6 +0,0 @@
-use day_21::part2::*;
-
-pub fn main() {
-    let input = include_str!("../../input-1.txt");
-    println!("Result: {:?}", process(input));
-}
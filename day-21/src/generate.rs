@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+
+/// A square garden `2 * size + 1` plots across, shaped like the real one: the start in the
+/// middle, its row and column and the border clear of rocks, and every plot reachable.
+pub fn generate(rng: &mut impl Rng, size: usize) -> String {
+    let side = 2 * size.max(2) + 1;
+    let middle = side / 2;
+
+    let mut rocks = HashSet::new();
+    for y in 1..side - 1 {
+        for x in 1..side - 1 {
+            if x != middle && y != middle && rng.gen_ratio(1, 6) {
+                rocks.insert((x, y));
+            }
+        }
+    }
+
+    // plots walled in by rocks could never be reached, so they may as well be rock
+    let mut reached = HashSet::from([(middle, middle)]);
+    let mut frontier = vec![(middle, middle)];
+    while let Some((x, y)) = frontier.pop() {
+        for next in [
+            (x + 1, y),
+            (x, y + 1),
+            (x.wrapping_sub(1), y),
+            (x, y.wrapping_sub(1)),
+        ] {
+            if next.0 < side && next.1 < side && !rocks.contains(&next) && reached.insert(next) {
+                frontier.push(next);
+            }
+        }
+    }
+
+    let mut out = String::new();
+    for y in 0..side {
+        for x in 0..side {
+            out.push(match (x, y) {
+                _ if (x, y) == (middle, middle) => 'S',
+                _ if reached.contains(&(x, y)) => '.',
+                _ => '#',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, part1, Plot};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn every_plot_can_be_reached() {
+        for seed in 0..3 {
+            let input = generate(&mut StdRng::seed_from_u64(seed), 10);
+            let garden = parse(&input).unwrap();
+            assert_eq!(garden.start, (10, 10));
+            assert!((0..21).all(|i| !garden.is_rock((i, 10)) && !garden.is_rock((10, i))));
+
+            // given long enough, the elf can stand on any plot an even number of steps away
+            let mut reached = HashSet::from([garden.start]);
+            for _ in 0..2 * 21 * 21 {
+                reached = garden.step(&reached);
+            }
+            let even = garden
+                .map
+                .iter()
+                .filter(|&((x, y), plot)| plot != &Plot::Rock && (x + y) % 2 == 0)
+                .count();
+            assert_eq!(reached.len(), even);
+            assert!(part1::process(&garden) <= even as u64);
+        }
+    }
+}
@@ -1,2 +1,116 @@
+use std::collections::HashSet;
+
+use aoc_core::{Animate, Generate, Grid, Image, ParseError, Solution, Source, Tile, Unsolvable};
+use rand::Rng;
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+pub mod render;
+pub mod step;
+
+pub struct Day21;
+
+pub type Position = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Plot {
+    Garden,
+    Rock,
+    Start,
+}
+
+impl Tile for Plot {
+    const EXPECTED: &'static str = "'.', '#' or 'S'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Plot::Garden),
+            '#' => Some(Plot::Rock),
+            'S' => Some(Plot::Start),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Plot::Garden => '.',
+            Plot::Rock => '#',
+            Plot::Start => 'S',
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Garden {
+    pub start: Position,
+    pub map: Grid<Plot>,
+}
+
+impl Garden {
+    pub fn is_rock(&self, (x, y): Position) -> bool {
+        self.map.get(x, y) == Some(&Plot::Rock)
+    }
+
+    /// Plots reachable in one more step from any of `reached`.
+    pub fn step(&self, reached: &HashSet<Position>) -> HashSet<Position> {
+        reached
+            .iter()
+            .flat_map(|pos| self.map.neighbours4(pos.0, pos.1))
+            .filter(|pos| !self.is_rock(*pos))
+            .collect()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Garden, ParseError> {
+    let source = Source::new(Day21::DAY, input);
+    let map = Grid::parse(&source, input)?;
+    let start = map
+        .position(|plot| plot == &Plot::Start)
+        .ok_or_else(|| source.error_after(input, "a starting plot 'S'"))?;
+
+    Ok(Garden { start, map })
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input<'a> = Garden;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Unsolvable> {
+        Ok(part1::process(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Unsolvable> {
+        part2::process(input)
+    }
+}
+
+impl Generate for Day21 {
+    fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+        generate::generate(rng, size)
+    }
+}
+
+impl Animate for Day21 {
+    fn frames(input: &Self::Input<'_>) -> Vec<Image> {
+        render::frames(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_missing_start() {
+        let err = parse("...\n.#.\n...\n").unwrap_err();
+        assert_eq!(err, ParseError::new(21, 4, 1, "a starting plot 'S'"));
+    }
+}
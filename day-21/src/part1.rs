@@ -1,92 +1,57 @@
 use std::collections::HashSet;
 
-pub fn process(input: &str) -> u64 {
-    let (start, rock_map, size) = parse_input(input);
+use log::trace;
 
-    print_garden(&size, &rock_map, &HashSet::new(), &start);
+use aoc_core::Tile;
 
-    let mut visited = HashSet::new();
-    visited.insert(start);
+use crate::{Garden, Position};
 
-    for i in 0..64 {
-        visited = visited
-            .iter()
-            .flat_map(|pos| get_neighbors(pos))
-            .filter(|pos| !rock_map.contains(pos))
-            .filter(|pos| pos.0 < size.0 && pos.1 < size.1)
-            .collect::<HashSet<Position>>();
-    }
-    println!();
-    print_garden(&size, &rock_map, &visited, &start);
+/// Steps the elf has left to take.
+pub const STEPS: usize = 64;
 
-    visited.len() as u64
+pub fn process(garden: &Garden) -> u64 {
+    count_reachable(garden, STEPS)
 }
 
-fn get_neighbors(pos: &Position) -> Vec<Position> {
-    let mut neighbors = Vec::new();
-    pos.0
-        .checked_sub(1)
-        .and_then(|x| Some(neighbors.push((x, pos.1))));
-    neighbors.push((pos.0 + 1, pos.1));
-    pos.1
-        .checked_sub(1)
-        .and_then(|y| Some(neighbors.push((pos.0, y))));
-    neighbors.push((pos.0, pos.1 + 1));
-    neighbors
-}
+fn count_reachable(garden: &Garden, steps: usize) -> u64 {
+    trace!("garden:\n{}", render_garden(garden, &HashSet::new()));
 
-type Position = (usize, usize);
-type Positions = HashSet<Position>;
+    let mut visited = HashSet::new();
+    visited.insert(garden.start);
 
-fn parse_input(input: &str) -> (Position, HashSet<Position>, Position) {
-    let (start_vec, map_vec): (Vec<Option<Position>>, Vec<Option<Position>>) = input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars().enumerate().filter_map(move |(x, c)| match c {
-                '#' => Some((None, Some((x, y)))),
-                'S' => Some((Some((x, y)), None)),
-                _ => None,
-            })
-        })
-        .unzip();
-    let map = map_vec
-        .into_iter()
-        .filter_map(|x| x)
-        .collect::<HashSet<Position>>();
-    let start = start_vec.into_iter().find_map(|x| x);
+    for _ in 0..steps {
+        visited = garden.step(&visited);
+    }
+    trace!("after {steps} steps:\n{}", render_garden(garden, &visited));
 
-    (
-        start.unwrap(),
-        map,
-        (input.lines().next().unwrap().len(), input.lines().count()),
-    )
+    visited.len() as u64
 }
 
-fn print_garden(size: &Position, rock_map: &Positions, visited_map: &Positions, start: &Position) {
-    for y in 0..size.1 {
-        for x in 0..size.0 {
-            if rock_map.contains(&(x, y)) {
-                print!("#");
-            } else if visited_map.contains(&(x, y)) {
-                print!("O");
-            } else if start == &(x, y) {
-                print!("S");
+fn render_garden(garden: &Garden, visited_map: &HashSet<Position>) -> String {
+    let mut out = String::new();
+    for (y, row) in garden.map.rows().enumerate() {
+        for (x, plot) in row.iter().enumerate() {
+            if visited_map.contains(&(x, y)) {
+                out.push('O');
             } else {
-                print!(".");
+                out.push(plot.to_char());
             }
         }
-        println!();
+        out.push('\n');
     }
+    out
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse;
+
     #[test]
     fn it_works() {
-        let result = process(
-            "...........
+        let result = count_reachable(
+            &parse(
+                "...........
 .....###.#.
 .###.##..#.
 ..#.#...#..
@@ -97,6 +62,9 @@ mod tests {
 .##.#.####.
 .##..##.##.
 ...........",
+            )
+            .unwrap(),
+            6,
         );
         assert_eq!(result, 16);
     }
@@ -1,156 +1,126 @@
 use colored::*;
-use itertools::Itertools;
+use log::trace;
 use std::collections::HashSet;
-use std::io;
-use std::io::prelude::*;
 
-fn pause() {
-    let mut stdin = io::stdin();
-    let mut stdout = io::stdout();
+use aoc_core::{Direction, Solution, Unsolvable};
+use aoc_math::extrapolate;
 
-    // We want the cursor to stay at the end of the line, so we print without a newline and flush manually.
-    write!(stdout, "Press any key to continue...").unwrap();
-    stdout.flush().unwrap();
+use crate::{Day21, Garden, Plot};
 
-    // Read a single byte and discard
-    let _ = stdin.read(&mut [0u8]).unwrap();
-}
-
-pub fn process(input: &str) -> u64 {
-    let (start, rock_map, size) = parse_input(input);
-
-    print_garden(&size, &rock_map, &HashSet::new(), &start);
-
-    let mut visited_one = HashSet::new();
-    visited_one.insert(start);
-
-    let mut visited_other = HashSet::new();
-
-    let mut cumulative_visited_one = HashSet::new();
-    let mut cumulative_visited_other = HashSet::new();
+const STEPS: i64 = 26501365;
 
-    for i in 1..=1375 {
-        (
-            visited_one,
-            visited_other,
-            cumulative_visited_one,
-            cumulative_visited_other,
-        ) = {
-            let v_o = get_steps(&visited_one, &cumulative_visited_other, &rock_map, &size);
-
-            cumulative_visited_other.extend(v_o.clone());
-            (
-                v_o,
-                visited_one,
-                cumulative_visited_other,
-                cumulative_visited_one,
-            )
-        };
-
-        //=====
-        // visited_one = get_steps(&visited_other, &cumulative_visited_one, &rock_map, &size);
-
-        // cumulative_visited_one.extend(visited_one.clone());
+pub fn process(garden: &Garden) -> Result<u64, Unsolvable> {
+    reachable(garden, STEPS)
+        .ok_or_else(|| Unsolvable::new(Day21::DAY, "the count of plots runs past a u64"))
+}
 
-        if i % 131 == 65 {
-            println!("{}: {}", i, cumulative_visited_one.len());
-        }
-        // pause();
-        // print_garden(&size, &rock_map, &cumulative_visited_other, &start);
-    }
+/// Plots reachable in exactly `steps` steps on the endlessly repeating garden, worked out
+/// from the walks that cross the first three copies of it, or `None` if the count
+/// overflows.
+fn reachable(garden: &Garden, steps: i64) -> Option<u64> {
+    let (start, rock_map, size) = to_signed(garden);
 
-    // (1..6).cartesian_product(1..8).for_each(|x| {
-    //     println!("{:?}", x);
-    // });
+    trace!(
+        "garden:\n{}",
+        render_garden(&size, &rock_map, &HashSet::new(), &start)
+    );
 
-    // let x = { 1 };
+    // the start row and column are clear, so the count grows quadratically
+    // every time the walk crosses another full copy of the garden
+    let (cycles, rem) = (steps / size.0, steps % size.0);
+    let samples = [0, 1, 2]
+        .map(|i| count_reachable(&start, &rock_map, &size, (rem + i * size.0) as usize) as i128);
 
-    // print_garden(&size, &rock_map, &visited_other, &start);
+    extrapolate(&samples, cycles as i128).and_then(|count| u64::try_from(count).ok())
+}
 
-    fn get_steps(
-        visited: &HashSet<Position>,
-        visited_other: &HashSet<Position>,
-        rock_map: &HashSet<Position>,
-        size: &Position,
-    ) -> HashSet<Position> {
-        visited
-            .iter()
-            .flat_map(|pos| get_neighbors(pos))
-            .filter(|pos| {
-                let rem = (pos.0.rem_euclid(size.0), pos.1.rem_euclid(size.1));
-                !rock_map.contains(&rem)
-            })
-            .filter(|pos| !visited_other.contains(pos))
-            .collect::<HashSet<Position>>()
+fn count_reachable(
+    start: &Position,
+    rock_map: &HashSet<Position>,
+    size: &Position,
+    steps: usize,
+) -> u64 {
+    let mut frontier = HashSet::from([*start]);
+    // plots reachable on even and odd steps respectively
+    let mut reached = [HashSet::from([*start]), HashSet::new()];
+
+    for i in 1..=steps {
+        frontier = get_steps(&frontier, &reached[i % 2], rock_map, size);
+        reached[i % 2].extend(frontier.iter().copied());
     }
 
-    cumulative_visited_one.len() as u64
+    reached[steps % 2].len() as u64
 }
 
-fn get_neighbors(pos: &Position) -> Vec<Position> {
-    let mut neighbors = Vec::new();
-    neighbors.push((pos.0 + 1, pos.1));
-    neighbors.push((pos.0 - 1, pos.1));
-    neighbors.push((pos.0, pos.1 + 1));
-    neighbors.push((pos.0, pos.1 - 1));
-    neighbors
+fn get_steps(
+    visited: &HashSet<Position>,
+    visited_other: &HashSet<Position>,
+    rock_map: &HashSet<Position>,
+    size: &Position,
+) -> HashSet<Position> {
+    visited
+        .iter()
+        .flat_map(|pos| Direction::ALL.map(|dir| dir.step(*pos)))
+        .filter(|pos| {
+            let rem = (pos.0.rem_euclid(size.0), pos.1.rem_euclid(size.1));
+            !rock_map.contains(&rem)
+        })
+        .filter(|pos| !visited_other.contains(pos))
+        .collect::<HashSet<Position>>()
 }
 
 type Position = (i64, i64);
 type Positions = HashSet<Position>;
 
-fn parse_input(input: &str) -> (Position, HashSet<Position>, Position) {
-    let (start_vec, map_vec): (Vec<Option<Position>>, Vec<Option<Position>>) = input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars().enumerate().filter_map(move |(x, c)| match c {
-                '#' => Some((None, Some((x as i64, y as i64)))),
-                'S' => Some((Some((x as i64, y as i64)), None)),
-                _ => None,
-            })
-        })
-        .unzip();
-    let map = map_vec
-        .into_iter()
-        .filter_map(|x| x)
-        .collect::<HashSet<Position>>();
-    let start = start_vec.into_iter().find_map(|x| x);
-
+/// Moves the garden onto signed coordinates, since the walk leaves the original map.
+fn to_signed(garden: &Garden) -> (Position, HashSet<Position>, Position) {
+    let signed = |(x, y): (usize, usize)| (x as i64, y as i64);
     (
-        start.unwrap(),
-        map,
-        (
-            input.lines().next().unwrap().len() as i64,
-            input.lines().count() as i64,
-        ),
+        signed(garden.start),
+        garden
+            .map
+            .iter()
+            .filter(|(_, plot)| **plot == Plot::Rock)
+            .map(|(pos, _)| signed(pos))
+            .collect(),
+        signed((garden.map.width(), garden.map.height())),
     )
 }
 
-fn print_garden(size: &Position, rock_map: &Positions, visited_map: &Positions, start: &Position) {
+fn render_garden(
+    size: &Position,
+    rock_map: &Positions,
+    visited_map: &Positions,
+    start: &Position,
+) -> String {
+    let mut out = String::new();
     for y in 0..size.1 {
         for x in 0..size.0 {
-            if rock_map.contains(&(x, y)) {
-                print!("{}", "#".blue());
+            let tile = if rock_map.contains(&(x, y)) {
+                "#".blue()
             } else if visited_map.contains(&(x, y)) {
-                print!("{}", "O".red());
+                "O".red()
             } else if start == &(x, y) {
-                print!("{}", "S".green());
+                "S".green()
             } else {
-                print!(".");
-            }
+                ".".normal()
+            };
+            out.push_str(&tile.to_string());
         }
-        println!();
+        out.push('\n');
     }
+    out
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[test]
-    fn it_works() {
-        let result = process(
-            "...........
+    use crate::{generate::generate, parse};
+    use proptest::prelude::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use rstest::rstest;
+
+    const EXAMPLE: &str = "...........
 .....###.#.
 .###.##..#.
 ..#.#...#..
@@ -160,8 +130,31 @@ mod tests {
 .......##..
 .##.#.####.
 .##..##.##.
-...........",
-        );
-        assert_eq!(result, 16);
+...........";
+
+    #[rstest]
+    #[case(6, 16)]
+    #[case(10, 50)]
+    #[case(50, 1594)]
+    #[case(100, 6536)]
+    fn it_works(#[case] steps: usize, #[case] expected: u64) {
+        let (start, rock_map, size) = to_signed(&parse(EXAMPLE).unwrap());
+        let result = count_reachable(&start, &rock_map, &size, steps);
+        assert_eq!(result, expected);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn shortcut_matches_walking(seed: u64, size in 2_usize..6, crossings in 3_i64..6) {
+            let garden = parse(&generate(&mut StdRng::seed_from_u64(seed), size)).unwrap();
+            let (start, rock_map, side) = to_signed(&garden);
+            let steps = crossings * side.0 + STEPS % side.0;
+            prop_assert_eq!(
+                reachable(&garden, steps),
+                Some(count_reachable(&start, &rock_map, &side, steps as usize))
+            );
+        }
     }
 }
@@ -0,0 +1,53 @@
+use aoc_core::{Image, Paint, Rgb, Step};
+
+use crate::{part1::STEPS, step::Walk, Garden, Plot};
+
+const REACHED: Rgb = Rgb(250, 179, 135);
+
+impl Paint for Plot {
+    fn paint(&self) -> Rgb {
+        match self {
+            Plot::Garden => Rgb(64, 160, 43),
+            Plot::Rock => Rgb(108, 112, 134),
+            Plot::Start => Rgb(30, 102, 245),
+        }
+    }
+}
+
+/// The plots reachable after each step of part one's walk, the start alone first.
+pub fn frames(garden: &Garden) -> Vec<Image> {
+    let base = Image::new(&garden.map);
+    let mut walk = Walk::new(garden);
+    let mut frames = Vec::with_capacity(STEPS + 1);
+    for step in 0..=STEPS {
+        if step > 0 {
+            walk.step();
+        }
+        let mut frame = base.clone();
+        frame.overlay(walk.reached().iter().copied(), REACHED);
+        frames.push(frame);
+    }
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn grows_the_frontier_one_step_per_frame() {
+        let garden = parse(include_str!("../../examples/21/tiled.txt")).unwrap();
+        let frames = frames(&garden);
+        assert_eq!(frames.len(), STEPS + 1);
+
+        let reached = |frame: &Image| {
+            (0..frame.height())
+                .flat_map(|y| (0..frame.width()).map(move |x| (x, y)))
+                .filter(|&(x, y)| frame.get(x, y) == Some(REACHED))
+                .count()
+        };
+        assert_eq!(reached(&frames[0]), 1);
+        assert_eq!(reached(&frames[STEPS]), 2453);
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use colored::Colorize;
+
+use aoc_core::{Event, Step};
+
+use crate::{part1::STEPS, Garden, Plot, Position};
+
+/// Plots the elf could be standing on, one step of the walk at a time.
+#[derive(Debug, Clone)]
+pub struct Walk<'a> {
+    garden: &'a Garden,
+    reached: HashSet<Position>,
+    steps: usize,
+}
+
+impl<'a> Walk<'a> {
+    pub fn new(garden: &'a Garden) -> Self {
+        Self {
+            garden,
+            reached: HashSet::from([garden.start]),
+            steps: 0,
+        }
+    }
+
+    pub fn reached(&self) -> &HashSet<Position> {
+        &self.reached
+    }
+}
+
+impl Step for Walk<'_> {
+    fn step(&mut self) -> Event {
+        self.reached = self.garden.step(&self.reached);
+        self.steps += 1;
+        if self.steps == STEPS {
+            Event::Breakpoint(format!(
+                "{STEPS} steps, the part one answer: {} plots",
+                self.reached.len()
+            ))
+        } else {
+            Event::Quiet
+        }
+    }
+
+    fn view(&self) -> String {
+        let mut out = format!(
+            "step {}, {} plots reached\n",
+            self.steps,
+            self.reached.len()
+        );
+        for (y, row) in self.garden.map.rows().enumerate() {
+            for (x, plot) in row.iter().enumerate() {
+                let tile = match plot {
+                    _ if self.reached.contains(&(x, y)) => "O".red().bold(),
+                    Plot::Garden => ".".green(),
+                    Plot::Rock => "#".bright_black(),
+                    Plot::Start => "S".blue().bold(),
+                };
+                out.push_str(&tile.to_string());
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn breaks_at_the_part_one_step_count() {
+        let garden = parse(include_str!("../../examples/21/tiled.txt")).unwrap();
+        let mut walk = Walk::new(&garden);
+        let steps = (1..)
+            .find(|_| matches!(walk.step(), Event::Breakpoint(_)))
+            .unwrap();
+        assert_eq!(steps, STEPS);
+        assert_eq!(walk.reached().len(), 2453);
+    }
+}